@@ -0,0 +1,131 @@
+//! Derive macros for [`easyduration`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use std::time::Duration;
+//! use easyduration_derive::EasyDurationEnum;
+//!
+//! #[derive(EasyDurationEnum)]
+//! enum Timeout {
+//!     #[duration("5s")]
+//!     Fast,
+//!     #[duration("1m30s")]
+//!     Slow,
+//! }
+//!
+//! assert_eq!(Timeout::Fast.duration(), Duration::from_secs(5));
+//! assert_eq!(Timeout::Slow.duration(), Duration::from_secs(90));
+//! ```
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, LitStr};
+
+/// Derives a `fn duration(&self) -> std::time::Duration` method for an enum.
+///
+/// Every variant needs a `#[duration("...")]` attribute holding a duration
+/// literal in the syntax accepted by [`easyduration::parse()`]. Literals are
+/// parsed at compile time, so an invalid literal is a compile error:
+///
+/// ```rust,compile_fail
+/// use easyduration_derive::EasyDurationEnum;
+///
+/// #[derive(EasyDurationEnum)]
+/// enum Timeout {
+///     #[duration("5 parsecs")]
+///     Fast,
+/// }
+/// ```
+#[proc_macro_derive(EasyDurationEnum, attributes(duration))]
+pub fn derive_easy_duration_enum(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let Data::Enum(data) = &input.data else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "EasyDurationEnum can only be derived for enums",
+        ));
+    };
+
+    let arms = data
+        .variants
+        .iter()
+        .map(|variant| {
+            let attr = variant
+                .attrs
+                .iter()
+                .find(|attr| attr.path().is_ident("duration"))
+                .ok_or_else(|| {
+                    Error::new_spanned(&variant.ident, "missing #[duration(\"...\")] attribute")
+                })?;
+            let literal: LitStr = attr.parse_args()?;
+            let duration = easyduration::parse(&literal.value())
+                .map_err(|err| Error::new_spanned(&literal, format!("invalid duration: {err}")))?;
+
+            let ident = &variant.ident;
+            let (secs, nanos) = (duration.as_secs(), duration.subsec_nanos());
+            Ok(quote! {
+                Self::#ident { .. } => ::std::time::Duration::new(#secs, #nanos),
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Returns the duration configured for this variant.
+            pub fn duration(&self) -> ::std::time::Duration {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expand_err(input: DeriveInput) -> String {
+        expand(input).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn test_invalid_literal() {
+        let err = expand_err(syn::parse_quote! {
+            enum Timeout {
+                #[duration("5 parsecs")]
+                Fast,
+            }
+        });
+        assert_eq!(err, "invalid duration: unknown unit 'parsecs'");
+    }
+
+    #[test]
+    fn test_missing_attribute() {
+        let err = expand_err(syn::parse_quote! {
+            enum Timeout {
+                #[duration("5s")]
+                Fast,
+                Slow,
+            }
+        });
+        assert_eq!(err, "missing #[duration(\"...\")] attribute");
+    }
+
+    #[test]
+    fn test_not_an_enum() {
+        let err = expand_err(syn::parse_quote! {
+            struct Timeout;
+        });
+        assert_eq!(err, "EasyDurationEnum can only be derived for enums");
+    }
+}
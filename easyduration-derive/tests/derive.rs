@@ -0,0 +1,30 @@
+use std::time::Duration;
+
+use easyduration::EasyDuration;
+use easyduration_derive::EasyDurationEnum;
+
+#[derive(EasyDurationEnum)]
+enum Tier {
+    #[duration("5s")]
+    Fast,
+    #[duration("30s")]
+    Normal,
+    #[duration("2 minutes, 30 seconds")]
+    Slow,
+    #[duration("1500ms")]
+    Custom(#[allow(dead_code)] u32),
+    #[duration("1d")]
+    Batch {
+        #[allow(dead_code)]
+        name: &'static str,
+    },
+}
+
+#[test]
+fn test_derive_easy_duration_enum() {
+    assert_eq!(Tier::Fast.duration(), 5.seconds());
+    assert_eq!(Tier::Normal.duration(), 30.seconds());
+    assert_eq!(Tier::Slow.duration(), 150.seconds());
+    assert_eq!(Tier::Custom(7).duration(), Duration::from_millis(1500));
+    assert_eq!(Tier::Batch { name: "nightly" }.duration(), 1.days());
+}
@@ -0,0 +1,71 @@
+//! A time budget that is deducted from as work proceeds.
+
+use std::time::Duration;
+
+/// A time budget that shrinks as work is consumed.
+///
+/// All deductions saturate, so [`Budget::remaining`] never drops below zero.
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{DurationExt, EasyDuration};
+///
+/// let mut budget = 30.seconds().budget();
+/// budget.consume(10.seconds());
+/// assert_eq!(budget.remaining(), 20.seconds());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Budget {
+    remaining: Duration,
+}
+
+impl Budget {
+    /// Creates a budget with `total` time available.
+    pub fn new(total: Duration) -> Self {
+        Self { remaining: total }
+    }
+
+    /// Deducts `d` from the budget.
+    pub fn consume(&mut self, d: Duration) {
+        self.remaining = self.remaining.saturating_sub(d);
+    }
+
+    /// Returns the time left in the budget.
+    pub fn remaining(&self) -> Duration {
+        self.remaining
+    }
+
+    /// Returns `true` if no time is left in the budget.
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining.is_zero()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DurationExt, EasyDuration};
+
+    #[test]
+    fn test_budget() {
+        let mut budget = 30.seconds().budget();
+        assert_eq!(budget, Budget::new(30.seconds()));
+        assert!(!budget.is_exhausted());
+
+        budget.consume(10.seconds());
+        assert_eq!(budget.remaining(), 20.seconds());
+        assert!(!budget.is_exhausted());
+
+        budget.consume(20.seconds());
+        assert_eq!(budget.remaining(), Duration::ZERO);
+        assert!(budget.is_exhausted());
+
+        // Consuming past exhaustion floors at zero.
+        budget.consume(5.seconds());
+        assert_eq!(budget.remaining(), Duration::ZERO);
+        assert!(budget.is_exhausted());
+
+        assert!(Duration::ZERO.budget().is_exhausted());
+    }
+}
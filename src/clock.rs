@@ -0,0 +1,93 @@
+//! Clock abstraction for time-dependent types.
+//!
+//! Types like [`Deadline`](crate::deadline::Deadline) read the current time
+//! through a [`Clock`], which defaults to [`SystemClock`]. Tests can swap in a
+//! [`MockClock`] to control time deterministically.
+
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+/// A source of the current time.
+pub trait Clock {
+    /// Returns the current instant.
+    fn now(&self) -> Instant;
+}
+
+/// The real system clock, backed by [`Instant::now`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// A clock that only moves when advanced manually, intended for tests.
+///
+/// Clones share the same time, so a clone can be handed to the type under
+/// test while the original is used to advance it.
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{clock::MockClock, deadline::Deadline, EasyDuration};
+///
+/// let clock = MockClock::new();
+/// let deadline = Deadline::with_clock(30.seconds(), clock.clone());
+///
+/// clock.advance(10.seconds());
+/// assert_eq!(deadline.remaining(), 20.seconds());
+/// ```
+#[derive(Clone, Debug)]
+pub struct MockClock {
+    now: Arc<Mutex<Instant>>,
+}
+
+impl MockClock {
+    /// Creates a mock clock starting at the current instant.
+    pub fn new() -> Self {
+        Self {
+            now: Arc::new(Mutex::new(Instant::now())),
+        }
+    }
+
+    /// Moves the clock forward by `d`.
+    pub fn advance(&self, d: Duration) {
+        *self.now.lock().unwrap() += d;
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> Instant {
+        *self.now.lock().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EasyDuration;
+
+    #[test]
+    fn test_mock_clock() {
+        let clock = MockClock::new();
+        let start = clock.now();
+        assert_eq!(clock.now(), start);
+
+        clock.advance(5.seconds());
+        assert_eq!(clock.now() - start, 5.seconds());
+
+        let shared = clock.clone();
+        shared.advance(1.seconds());
+        assert_eq!(clock.now() - start, 6.seconds());
+    }
+}
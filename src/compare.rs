@@ -0,0 +1,81 @@
+//! Comparisons of [`std::time::Duration`] values.
+
+use std::{cmp::Ordering, time::Duration};
+
+/// Returns `true` if `a` and `b` have the same number of whole seconds.
+///
+/// Sub-second parts are ignored.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use easyduration::compare::eq_seconds;
+///
+/// assert!(eq_seconds(Duration::from_millis(1400), Duration::from_millis(1900)));
+/// ```
+pub fn eq_seconds(a: Duration, b: Duration) -> bool {
+    a.as_secs() == b.as_secs()
+}
+
+/// Compares the whole seconds of `a` and `b`, ignoring sub-second parts.
+pub fn cmp_seconds(a: Duration, b: Duration) -> Ordering {
+    a.as_secs().cmp(&b.as_secs())
+}
+
+/// Returns the change from `from` to `to` in percent of `from`.
+///
+/// The result is positive if `to` is longer and negative if it is shorter, so
+/// for timings regressions are positive. A zero `from` yields `0.0` if `to`
+/// is zero as well and [`f64::INFINITY`] otherwise.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use easyduration::{compare::pct_change, EasyDuration};
+///
+/// assert_eq!(pct_change(8.seconds(), 9.seconds()), 12.5);
+/// assert_eq!(pct_change(2.seconds(), 1.seconds()), -50.0);
+/// ```
+pub fn pct_change(from: Duration, to: Duration) -> f64 {
+    if from.is_zero() {
+        return if to.is_zero() { 0.0 } else { f64::INFINITY };
+    }
+    let (from, to) = (from.as_nanos() as f64, to.as_nanos() as f64);
+    (to - from) / from * 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EasyDuration;
+
+    #[test]
+    fn test_eq_cmp_seconds() {
+        let ms = Duration::from_millis;
+
+        assert!(eq_seconds(ms(1400), ms(1900)));
+        assert!(!eq_seconds(ms(1900), ms(2000)));
+        assert!(eq_seconds(Duration::ZERO, ms(999)));
+
+        assert_eq!(cmp_seconds(ms(1400), ms(1900)), Ordering::Equal);
+        assert_eq!(cmp_seconds(ms(1900), ms(2000)), Ordering::Less);
+        assert_eq!(cmp_seconds(ms(3000), ms(2999)), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_pct_change() {
+        let ms = Duration::from_millis;
+
+        assert_eq!(pct_change(8.seconds(), 9.seconds()), 12.5);
+        assert_eq!(pct_change(1.seconds(), 2.seconds()), 100.0);
+        assert_eq!(pct_change(2.seconds(), 1.seconds()), -50.0);
+        assert_eq!(pct_change(ms(400), ms(300)), -25.0);
+        assert_eq!(pct_change(1.seconds(), 1.seconds()), 0.0);
+        assert_eq!(pct_change(1.seconds(), Duration::ZERO), -100.0);
+
+        assert_eq!(pct_change(Duration::ZERO, Duration::ZERO), 0.0);
+        assert_eq!(pct_change(Duration::ZERO, ms(1)), f64::INFINITY);
+    }
+}
@@ -0,0 +1,35 @@
+//! Commonly used durations as constants.
+
+use std::time::Duration;
+
+/// Half a second.
+pub const HALF_SECOND: Duration = Duration::from_millis(500);
+/// One second.
+pub const ONE_SECOND: Duration = Duration::from_secs(1);
+/// One minute.
+pub const ONE_MINUTE: Duration = Duration::from_secs(60);
+/// One hour.
+pub const ONE_HOUR: Duration = Duration::from_secs(60 * 60);
+/// One day of 24 hours.
+pub const ONE_DAY: Duration = Duration::from_secs(60 * 60 * 24);
+/// One week of 7 days.
+pub const ONE_WEEK: Duration = Duration::from_secs(60 * 60 * 24 * 7);
+/// One year of 365 days.
+pub const ONE_YEAR: Duration = Duration::from_secs(60 * 60 * 24 * 365);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{EasyDuration, Unit};
+
+    #[test]
+    fn test_consts() {
+        assert_eq!(HALF_SECOND, Duration::from_millis(500));
+        assert_eq!(ONE_SECOND, 1.seconds());
+        assert_eq!(ONE_MINUTE, 1.minutes());
+        assert_eq!(ONE_HOUR, 1.hours());
+        assert_eq!(ONE_DAY, 1.days());
+        assert_eq!(ONE_WEEK, Unit::Weeks.duration());
+        assert_eq!(ONE_YEAR, 1.years());
+    }
+}
@@ -0,0 +1,451 @@
+//! Conversions from [`std::time::Duration`] back to plain numeric values.
+
+use std::{fmt, time::Duration};
+
+use crate::ParseError;
+
+/// Error returned when a [`Duration`] does not fit into the requested integer
+/// type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OverflowError;
+
+impl fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("duration does not fit into the target integer type")
+    }
+}
+
+impl std::error::Error for OverflowError {}
+
+/// Returns the number of whole seconds in `d`.
+///
+/// The sub-second remainder is discarded.
+pub fn try_as_seconds_u32(d: Duration) -> Result<u32, OverflowError> {
+    whole_units_u32(d, 1)
+}
+
+/// Returns the number of whole minutes in `d`.
+///
+/// The remainder is discarded.
+pub fn try_as_minutes_u32(d: Duration) -> Result<u32, OverflowError> {
+    whole_units_u32(d, 60)
+}
+
+/// Returns the number of whole hours in `d`.
+///
+/// The remainder is discarded.
+pub fn try_as_hours_u32(d: Duration) -> Result<u32, OverflowError> {
+    whole_units_u32(d, 60 * 60)
+}
+
+/// Returns the number of whole days in `d`.
+///
+/// A day is defined as 24 hours. The remainder is discarded.
+pub fn try_as_days_u32(d: Duration) -> Result<u32, OverflowError> {
+    whole_units_u32(d, 60 * 60 * 24)
+}
+
+/// Returns the number of whole years in `d`.
+///
+/// A year is defined as 365 days. The remainder is discarded.
+pub fn try_as_years_u32(d: Duration) -> Result<u32, OverflowError> {
+    whole_units_u32(d, 60 * 60 * 24 * 365)
+}
+
+/// Creates a [`Duration`] from whole minutes.
+///
+/// Unlike [`EasyDuration::minutes`](crate::EasyDuration::minutes) this
+/// returns an error instead of panicking if the result overflows.
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{convert::from_minutes_u64, EasyDuration};
+///
+/// assert_eq!(from_minutes_u64(90), Ok(90.minutes()));
+/// assert!(from_minutes_u64(u64::MAX).is_err());
+/// ```
+pub fn from_minutes_u64(minutes: u64) -> Result<Duration, OverflowError> {
+    from_units_u64(minutes, 60)
+}
+
+/// Creates a [`Duration`] from whole hours, returning an error if the result
+/// overflows.
+pub fn from_hours_u64(hours: u64) -> Result<Duration, OverflowError> {
+    from_units_u64(hours, 60 * 60)
+}
+
+/// Creates a [`Duration`] from whole days of 24 hours, returning an error if
+/// the result overflows.
+pub fn from_days_u64(days: u64) -> Result<Duration, OverflowError> {
+    from_units_u64(days, 60 * 60 * 24)
+}
+
+/// Splits `d` into whole `(hours, minutes, seconds)`.
+///
+/// Minutes and seconds are always below 60 while hours are unbounded. The
+/// sub-second remainder is discarded.
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{convert::hms, EasyDuration};
+///
+/// assert_eq!(hms(3725.seconds()), (1, 2, 5));
+/// ```
+pub fn hms(d: Duration) -> (u64, u8, u8) {
+    let secs = d.as_secs();
+    (secs / 3600, (secs / 60 % 60) as u8, (secs % 60) as u8)
+}
+
+/// Returns `d` as fractional seconds.
+pub fn secs_f64(d: Duration) -> f64 {
+    d.as_secs_f64()
+}
+
+/// Returns `d` as fractional milliseconds.
+pub fn millis_f64(d: Duration) -> f64 {
+    d.as_nanos() as f64 / 1e6
+}
+
+/// Returns `d` as fractional microseconds.
+pub fn micros_f64(d: Duration) -> f64 {
+    d.as_nanos() as f64 / 1e3
+}
+
+/// Returns `d` as fractional milliseconds, as used by JavaScript APIs like
+/// `setTimeout` and `performance.now()`.
+pub fn to_js_millis(d: Duration) -> f64 {
+    d.as_secs_f64() * 1000.0
+}
+
+/// Creates a [`Duration`] from fractional JavaScript milliseconds.
+///
+/// Negative values are clamped to zero, matching how `setTimeout` treats
+/// them. Returns `None` for NaN, infinite or too large values.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use easyduration::convert::from_js_millis;
+///
+/// assert_eq!(from_js_millis(1500.0), Some(Duration::from_millis(1500)));
+/// assert_eq!(from_js_millis(f64::NAN), None);
+/// ```
+pub fn from_js_millis(ms: f64) -> Option<Duration> {
+    if !ms.is_finite() {
+        return None;
+    }
+    Duration::try_from_secs_f64(ms.max(0.0) / 1000.0).ok()
+}
+
+/// Returns `d` in RTP timestamp units of a `clock_rate` Hz media clock,
+/// rounded to the nearest unit.
+///
+/// Saturates at [`u64::MAX`]. A zero `clock_rate` yields zero units.
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{convert::to_rtp_units, EasyDuration};
+///
+/// assert_eq!(to_rtp_units(1.seconds(), 90_000), 90_000);
+/// ```
+pub fn to_rtp_units(d: Duration, clock_rate: u32) -> u64 {
+    let units = (d.as_nanos() * u128::from(clock_rate) + NANOS_PER_SEC / 2) / NANOS_PER_SEC;
+    units.try_into().unwrap_or(u64::MAX)
+}
+
+/// Creates a [`Duration`] from RTP timestamp units of a `clock_rate` Hz media
+/// clock, rounded to the nearest nanosecond.
+///
+/// # Panics
+///
+/// Panics if `clock_rate` is zero.
+pub fn from_rtp_units(units: u64, clock_rate: u32) -> Duration {
+    assert!(clock_rate != 0, "clock rate must not be zero");
+    let rate = u128::from(clock_rate);
+    let nanos = (u128::from(units) * NANOS_PER_SEC + rate / 2) / rate;
+    crate::checked_from_nanos(nanos).unwrap()
+}
+
+/// Creates a [`Duration`] from an exact number of seconds given as a ratio.
+///
+/// The fractional part is rounded to the nearest nanosecond, rounding halves
+/// up. Saturates at [`Duration::MAX`]. Requires the `num` feature.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use easyduration::convert::from_ratio_seconds;
+/// use num_rational::Ratio;
+///
+/// assert_eq!(from_ratio_seconds(Ratio::new(3, 2)), Duration::from_millis(1500));
+/// ```
+#[cfg(feature = "num")]
+pub fn from_ratio_seconds(r: num_rational::Ratio<u64>) -> Duration {
+    let (numer, denom) = (u128::from(*r.numer()), u128::from(*r.denom()));
+    let nanos = (numer * NANOS_PER_SEC + denom / 2) / denom;
+    crate::checked_from_nanos(nanos).unwrap_or(Duration::MAX)
+}
+
+/// Returns `d` in Windows `FILETIME` ticks of 100 nanoseconds.
+///
+/// The remainder below one tick is discarded. Saturates at [`u64::MAX`].
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{convert::to_filetime_ticks, EasyDuration};
+///
+/// assert_eq!(to_filetime_ticks(1.seconds()), 10_000_000);
+/// ```
+pub fn to_filetime_ticks(d: Duration) -> u64 {
+    (d.as_nanos() / 100).try_into().unwrap_or(u64::MAX)
+}
+
+/// Creates a [`Duration`] from Windows `FILETIME` ticks of 100 nanoseconds.
+pub fn from_filetime_ticks(ticks: u64) -> Duration {
+    const TICKS_PER_SEC: u64 = 10_000_000;
+    Duration::new(ticks / TICKS_PER_SEC, (ticks % TICKS_PER_SEC) as u32 * 100)
+}
+
+/// Encodes the whole milliseconds of `d` as a compact lowercase base36 token,
+/// like `"2s"` for 100 milliseconds.
+///
+/// Sub-millisecond parts are discarded and the count saturates at
+/// [`u64::MAX`]. Use [`from_token`] to decode it.
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{convert::{from_token, to_token}, EasyDuration};
+///
+/// let token = to_token(1.hours());
+/// assert_eq!(token, "255s0");
+/// assert_eq!(from_token(&token), Ok(1.hours()));
+/// ```
+pub fn to_token(d: Duration) -> String {
+    let mut millis: u64 = d.as_millis().try_into().unwrap_or(u64::MAX);
+    if millis == 0 {
+        return "0".to_string();
+    }
+    let mut digits = Vec::new();
+    while millis > 0 {
+        digits.push(char::from_digit((millis % 36) as u32, 36).unwrap());
+        millis /= 36;
+    }
+    digits.iter().rev().collect()
+}
+
+/// Decodes a token created by [`to_token`].
+///
+/// Uppercase digits are accepted as well.
+pub fn from_token(s: &str) -> Result<Duration, ParseError> {
+    if s.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    if !s.bytes().all(|b| b.is_ascii_alphanumeric()) {
+        return Err(ParseError::InvalidNumber);
+    }
+    let millis = u64::from_str_radix(s, 36).map_err(|_| ParseError::Overflow)?;
+    Ok(Duration::from_millis(millis))
+}
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+fn from_units_u64(count: u64, unit_secs: u64) -> Result<Duration, OverflowError> {
+    count
+        .checked_mul(unit_secs)
+        .map(Duration::from_secs)
+        .ok_or(OverflowError)
+}
+
+fn whole_units_u32(d: Duration, unit_secs: u64) -> Result<u32, OverflowError> {
+    (d.as_secs() / unit_secs)
+        .try_into()
+        .map_err(|_| OverflowError)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EasyDuration;
+
+    #[test]
+    fn test_try_as_u32() {
+        assert_eq!(try_as_seconds_u32(Duration::from_millis(1500)), Ok(1));
+        assert_eq!(try_as_minutes_u32(90.seconds()), Ok(1));
+        assert_eq!(try_as_hours_u32(3.hours()), Ok(3));
+        assert_eq!(try_as_days_u32(49.hours()), Ok(2));
+        assert_eq!(try_as_years_u32(800.days()), Ok(2));
+
+        let max = u64::from(u32::MAX);
+        assert_eq!(try_as_seconds_u32(max.seconds()), Ok(u32::MAX));
+        assert_eq!(try_as_seconds_u32((max + 1).seconds()), Err(OverflowError));
+        assert_eq!(try_as_minutes_u32((max + 1).minutes()), Err(OverflowError));
+    }
+
+    #[test]
+    fn test_from_units_u64() {
+        assert_eq!(from_minutes_u64(90), Ok(90.minutes()));
+        assert_eq!(from_hours_u64(3), Ok(3.hours()));
+        assert_eq!(from_days_u64(2), Ok(2.days()));
+        assert_eq!(from_minutes_u64(0), Ok(Duration::ZERO));
+
+        for (f, unit_secs) in [
+            (from_minutes_u64 as fn(u64) -> _, 60),
+            (from_hours_u64, 60 * 60),
+            (from_days_u64, 60 * 60 * 24),
+        ] {
+            let max = u64::MAX / unit_secs;
+            assert_eq!(f(max), Ok(Duration::from_secs(max * unit_secs)));
+            assert_eq!(f(max + 1), Err(OverflowError));
+            assert_eq!(f(u64::MAX), Err(OverflowError));
+        }
+    }
+
+    #[test]
+    fn test_hms() {
+        assert_eq!(hms(3725.seconds()), (1, 2, 5));
+        assert_eq!(hms(Duration::ZERO), (0, 0, 0));
+        assert_eq!(hms(Duration::from_millis(59_999)), (0, 0, 59));
+        assert_eq!(hms(100.hours() + 59.minutes()), (100, 59, 0));
+        assert_eq!(hms(Duration::MAX), (u64::MAX / 3600, 0, 15));
+    }
+
+    #[test]
+    fn test_f64_accessors() {
+        for d in [
+            Duration::ZERO,
+            Duration::from_nanos(1),
+            Duration::from_micros(1500),
+            Duration::from_millis(1500),
+            90.minutes(),
+        ] {
+            assert_eq!(secs_f64(d), d.as_secs_f64());
+            assert_eq!(millis_f64(d).trunc(), d.as_millis() as f64);
+            assert_eq!(micros_f64(d).trunc(), d.as_micros() as f64);
+        }
+        assert_eq!(millis_f64(Duration::from_micros(1500)), 1.5);
+        assert_eq!(micros_f64(Duration::from_nanos(1500)), 1.5);
+        assert_eq!(millis_f64(Duration::from_nanos(1)), 1e-6);
+        assert_eq!(micros_f64(Duration::from_nanos(1)), 1e-3);
+    }
+
+    #[test]
+    fn test_js_millis() {
+        let d = Duration::from_millis(1500);
+        assert_eq!(to_js_millis(d), 1500.0);
+        assert_eq!(from_js_millis(1500.0), Some(d));
+        assert_eq!(from_js_millis(to_js_millis(d)), Some(d));
+        assert_eq!(to_js_millis(Duration::from_micros(250)), 0.25);
+        assert_eq!(from_js_millis(0.25), Some(Duration::from_micros(250)));
+
+        assert_eq!(from_js_millis(-20.0), Some(Duration::ZERO));
+        assert_eq!(from_js_millis(f64::NAN), None);
+        assert_eq!(from_js_millis(f64::INFINITY), None);
+        assert_eq!(from_js_millis(f64::NEG_INFINITY), None);
+        assert_eq!(from_js_millis(f64::MAX), None);
+    }
+
+    #[test]
+    fn test_rtp_units() {
+        assert_eq!(to_rtp_units(1.seconds(), 90_000), 90_000);
+        assert_eq!(to_rtp_units(Duration::from_millis(20), 48_000), 960);
+        assert_eq!(to_rtp_units(Duration::from_micros(11), 90_000), 1);
+        assert_eq!(to_rtp_units(1.seconds(), 0), 0);
+        assert_eq!(to_rtp_units(Duration::MAX, 90_000), u64::MAX);
+
+        assert_eq!(from_rtp_units(90_000, 90_000), 1.seconds());
+        assert_eq!(from_rtp_units(960, 48_000), Duration::from_millis(20));
+        assert_eq!(from_rtp_units(1, 90_000), Duration::from_nanos(11_111));
+        assert_eq!(from_rtp_units(2, 3), Duration::from_nanos(666_666_667));
+        assert_eq!(to_rtp_units(from_rtp_units(12_345, 90_000), 90_000), 12_345);
+    }
+
+    #[test]
+    fn test_filetime_ticks() {
+        assert_eq!(to_filetime_ticks(1.seconds()), 10_000_000);
+        assert_eq!(from_filetime_ticks(10_000_000), 1.seconds());
+        assert_eq!(to_filetime_ticks(Duration::from_nanos(199)), 1);
+        assert_eq!(from_filetime_ticks(1), Duration::from_nanos(100));
+        assert_eq!(to_filetime_ticks(Duration::MAX), u64::MAX);
+
+        for ticks in [0, 1, 9_999_999, 10_000_001, 123_456_789_012, u64::MAX] {
+            assert_eq!(to_filetime_ticks(from_filetime_ticks(ticks)), ticks);
+        }
+    }
+
+    #[test]
+    fn test_token() {
+        assert_eq!(to_token(Duration::ZERO), "0");
+        assert_eq!(to_token(Duration::from_millis(35)), "z");
+        assert_eq!(to_token(Duration::from_millis(36)), "10");
+        assert_eq!(to_token(100.seconds()), "255s");
+        assert_eq!(to_token(Duration::from_millis(100)), "2s");
+        assert_eq!(to_token(Duration::from_micros(1999)), "1");
+        assert_eq!(to_token(Duration::MAX), "3w5e11264sgsf");
+
+        for d in [
+            Duration::ZERO,
+            Duration::from_millis(1),
+            1.hours(),
+            400.days(),
+        ] {
+            assert_eq!(from_token(&to_token(d)), Ok(d));
+        }
+        assert_eq!(
+            from_token("3w5e11264sgsf"),
+            Ok(Duration::from_millis(u64::MAX))
+        );
+        assert_eq!(from_token("255S"), Ok(100.seconds()));
+
+        assert_eq!(from_token(""), Err(ParseError::Empty));
+        assert_eq!(from_token("2-5"), Err(ParseError::InvalidNumber));
+        assert_eq!(from_token("+1"), Err(ParseError::InvalidNumber));
+        assert_eq!(from_token(" 1"), Err(ParseError::InvalidNumber));
+        assert_eq!(from_token("3w5e11264sgsg"), Err(ParseError::Overflow));
+    }
+
+    #[cfg(feature = "num")]
+    #[test]
+    fn test_from_ratio_seconds() {
+        use num_rational::Ratio;
+
+        assert_eq!(
+            from_ratio_seconds(Ratio::new(3, 2)),
+            Duration::from_millis(1500)
+        );
+        assert_eq!(
+            from_ratio_seconds(Ratio::new(6, 4)),
+            Duration::from_millis(1500)
+        );
+        assert_eq!(from_ratio_seconds(Ratio::from_integer(90)), 90.seconds());
+        assert_eq!(from_ratio_seconds(Ratio::new(0, 7)), Duration::ZERO);
+        assert_eq!(
+            from_ratio_seconds(Ratio::new(1, 3)),
+            Duration::from_nanos(333_333_333)
+        );
+        assert_eq!(
+            from_ratio_seconds(Ratio::new(2, 3)),
+            Duration::from_nanos(666_666_667)
+        );
+        assert_eq!(
+            from_ratio_seconds(Ratio::new(1, 2_000_000_000)),
+            Duration::from_nanos(1)
+        );
+        assert_eq!(
+            from_ratio_seconds(Ratio::from_integer(u64::MAX)),
+            Duration::from_secs(u64::MAX)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "clock rate must not be zero")]
+    fn test_from_rtp_units_zero_rate() {
+        from_rtp_units(1, 0);
+    }
+}
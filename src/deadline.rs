@@ -0,0 +1,104 @@
+//! A deadline relative to the moment it was created.
+
+use std::time::{Duration, Instant};
+
+use crate::clock::{Clock, SystemClock};
+
+/// A point in time after which an operation is considered expired.
+///
+/// All queries are evaluated against the current time of the [`Clock`] at
+/// call time, which defaults to the [`SystemClock`].
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{DurationExt, EasyDuration};
+///
+/// let deadline = 30.seconds().deadline();
+/// assert!(!deadline.is_passed());
+/// assert!(deadline.remaining() <= 30.seconds());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Deadline<C: Clock = SystemClock> {
+    /// `None` if the timeout is too large to be represented as an [`Instant`],
+    /// in which case the deadline never passes.
+    expires_at: Option<Instant>,
+    clock: C,
+}
+
+impl Deadline {
+    /// Creates a deadline that passes `timeout` from now.
+    pub fn new(timeout: Duration) -> Self {
+        Self::with_clock(timeout, SystemClock)
+    }
+}
+
+impl<C: Clock> Deadline<C> {
+    /// Creates a deadline that passes `timeout` from the current time of
+    /// `clock`.
+    pub fn with_clock(timeout: Duration, clock: C) -> Self {
+        Self {
+            expires_at: clock.now().checked_add(timeout),
+            clock,
+        }
+    }
+
+    /// Returns `true` if the deadline has passed.
+    pub fn is_passed(&self) -> bool {
+        self.remaining().is_zero()
+    }
+
+    /// Returns the time left until the deadline passes, or zero if it already
+    /// has.
+    pub fn remaining(&self) -> Duration {
+        match self.expires_at {
+            Some(expires_at) => expires_at.saturating_duration_since(self.clock.now()),
+            None => Duration::MAX,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{clock::MockClock, DurationExt, EasyDuration};
+
+    #[test]
+    fn test_deadline() {
+        let deadline = 30.seconds().deadline();
+        assert!(!deadline.is_passed());
+
+        let before = deadline.remaining();
+        assert!(before <= 30.seconds());
+        std::thread::sleep(Duration::from_millis(20));
+        let after = deadline.remaining();
+        assert!(after < before);
+        assert!(!deadline.is_passed());
+
+        let deadline = Duration::from_millis(10).deadline();
+        std::thread::sleep(Duration::from_millis(20));
+        assert!(deadline.is_passed());
+        assert_eq!(deadline.remaining(), Duration::ZERO);
+
+        assert!(Duration::ZERO.deadline().is_passed());
+        assert!(!Duration::MAX.deadline().is_passed());
+    }
+
+    #[test]
+    fn test_deadline_mock_clock() {
+        let clock = MockClock::new();
+        let deadline = Deadline::with_clock(30.seconds(), clock.clone());
+        assert_eq!(deadline.remaining(), 30.seconds());
+
+        clock.advance(10.seconds());
+        assert_eq!(deadline.remaining(), 20.seconds());
+        assert!(!deadline.is_passed());
+
+        clock.advance(20.seconds());
+        assert_eq!(deadline.remaining(), Duration::ZERO);
+        assert!(deadline.is_passed());
+
+        clock.advance(1.hours());
+        assert!(deadline.is_passed());
+    }
+}
@@ -0,0 +1,119 @@
+//! Reading durations from environment variables.
+
+use std::{env, fmt, time::Duration};
+
+use crate::ParseError;
+
+/// Error returned by [`try_from_env`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EnvError {
+    /// The variable is not set.
+    NotPresent { var: String },
+    /// The variable is not valid unicode.
+    NotUnicode { var: String },
+    /// The value of the variable is not a valid duration.
+    Invalid { var: String, error: ParseError },
+}
+
+impl fmt::Display for EnvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EnvError::NotPresent { var } => write!(f, "environment variable {var} is not set"),
+            EnvError::NotUnicode { var } => {
+                write!(f, "environment variable {var} is not valid unicode")
+            }
+            EnvError::Invalid { var, error } => {
+                write!(
+                    f,
+                    "environment variable {var} is not a valid duration: {error}"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for EnvError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            EnvError::Invalid { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Reads the environment variable `var` and parses it with
+/// [`parse()`](crate::parse()).
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{env::try_from_env, EasyDuration};
+///
+/// std::env::set_var("EXAMPLE_TIMEOUT", "30s");
+/// assert_eq!(try_from_env("EXAMPLE_TIMEOUT"), Ok(30.seconds()));
+/// ```
+pub fn try_from_env(var: &str) -> Result<Duration, EnvError> {
+    let value = env::var(var).map_err(|err| match err {
+        env::VarError::NotPresent => EnvError::NotPresent {
+            var: var.to_string(),
+        },
+        env::VarError::NotUnicode(_) => EnvError::NotUnicode {
+            var: var.to_string(),
+        },
+    })?;
+    crate::parse(&value).map_err(|error| EnvError::Invalid {
+        var: var.to_string(),
+        error,
+    })
+}
+
+/// Reads the environment variable `var` and parses it with
+/// [`parse()`](crate::parse()), returning `default` if it is not set or
+/// invalid.
+///
+/// Use [`try_from_env`] to tell a missing variable from an invalid one.
+pub fn from_env(var: &str, default: Duration) -> Duration {
+    try_from_env(var).unwrap_or(default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EasyDuration;
+
+    #[test]
+    fn test_from_env() {
+        let var = "EASYDURATION_TEST_FROM_ENV";
+        let default = 10.seconds();
+
+        env::remove_var(var);
+        assert_eq!(
+            try_from_env(var),
+            Err(EnvError::NotPresent {
+                var: var.to_string()
+            })
+        );
+        assert_eq!(from_env(var, default), default);
+
+        env::set_var(var, "1m30s");
+        assert_eq!(try_from_env(var), Ok(90.seconds()));
+        assert_eq!(from_env(var, default), 90.seconds());
+
+        env::set_var(var, "soon");
+        let err = try_from_env(var).unwrap_err();
+        assert_eq!(
+            err,
+            EnvError::Invalid {
+                var: var.to_string(),
+                error: ParseError::InvalidNumber
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "environment variable EASYDURATION_TEST_FROM_ENV is not a valid duration: expected a number"
+        );
+        assert_eq!(from_env(var, default), default);
+
+        env::remove_var(var);
+    }
+}
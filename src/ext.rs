@@ -0,0 +1,410 @@
+//! The [`DurationExt`] extension trait for [`std::time::Duration`].
+
+use std::time::{Duration, SystemTime};
+
+use crate::{budget::Budget, deadline::Deadline, keepalive::KeepAlive};
+
+/// Extension methods for [`std::time::Duration`].
+///
+/// These complement [`EasyDuration`](crate::EasyDuration) and are meant to be
+/// chained onto a constructed duration, like `30.seconds().budget()`.
+///
+/// The trait is sealed and only implemented for [`Duration`], so methods can
+/// be added without breaking downstream code.
+pub trait DurationExt: Copy + sealed::Sealed {
+    /// Creates a [`Budget`] with `self` as the total available time.
+    fn budget(self) -> Budget;
+
+    /// Creates a [`Deadline`] that passes `self` from now.
+    fn deadline(self) -> Deadline;
+
+    /// Creates a [`KeepAlive`] that expires after `self` without activity.
+    fn keepalive(self) -> KeepAlive;
+
+    /// Multiplies `self` by `factor`, capping the result at `cap`.
+    ///
+    /// This is a single step of a multiplicative backoff, like
+    /// `interval = interval.grow(2.0, max)`. Products too large for a
+    /// [`Duration`] saturate to `cap`, a negative or NaN `factor` yields zero.
+    fn grow(self, factor: f64, cap: Duration) -> Duration;
+
+    /// Randomly perturbs `self` by up to `±fraction` of its length, using a
+    /// deterministic generator seeded with `seed`.
+    ///
+    /// The same seed always yields the same result, which keeps tests of
+    /// jittered code reproducible. The sign of `fraction` is ignored, the
+    /// result never drops below zero and saturates at [`Duration::MAX`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use easyduration::{DurationExt, EasyDuration};
+    ///
+    /// let d = 10.seconds().jitter_seeded(0.1, 42);
+    /// assert!(d >= 9.seconds() && d <= 11.seconds());
+    /// assert_eq!(d, 10.seconds().jitter_seeded(0.1, 42));
+    /// ```
+    fn jitter_seeded(self, fraction: f64, seed: u64) -> Duration;
+
+    /// Returns `true` if `self` is an exact multiple of `unit`.
+    ///
+    /// Always returns `false` for a zero `unit`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use easyduration::{DurationExt, EasyDuration};
+    ///
+    /// assert!(120.seconds().is_whole(1.minutes()));
+    /// assert!(!90.seconds().is_whole(1.minutes()));
+    /// ```
+    fn is_whole(self, unit: Duration) -> bool;
+
+    /// Discards the sub-second part of `self`.
+    fn truncate_subsec(self) -> Duration;
+
+    /// Rounds `self` to the nearest whole second, rounding halves up.
+    ///
+    /// Saturates at the largest whole number of seconds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use easyduration::{DurationExt, EasyDuration};
+    ///
+    /// let d = Duration::from_millis(1500);
+    /// assert_eq!(d.truncate_subsec(), 1.seconds());
+    /// assert_eq!(d.round_subsec(), 2.seconds());
+    /// ```
+    fn round_subsec(self) -> Duration;
+
+    /// Returns a random duration between `self` and `other` (inclusive).
+    ///
+    /// The bounds may be given in either order. Requires the `rand` feature,
+    /// see [`random_between`](crate::random::random_between).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use easyduration::{DurationExt, EasyDuration};
+    ///
+    /// let timeout = 1.seconds().to(3.seconds());
+    /// assert!(timeout >= 1.seconds() && timeout <= 3.seconds());
+    /// ```
+    #[cfg(feature = "rand")]
+    fn to(self, other: Duration) -> Duration;
+
+    /// Randomly perturbs `self` by up to `±amount`.
+    ///
+    /// Unlike [`jitter_seeded`](DurationExt::jitter_seeded) the jitter window
+    /// doesn't scale with `self`. The result is uniform over
+    /// `[self - amount, self + amount]`, floored at zero and saturating at
+    /// [`Duration::MAX`]. Requires the `rand` feature.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use easyduration::{DurationExt, EasyDuration};
+    ///
+    /// let d = 1.seconds().jitter_abs(Duration::from_millis(50));
+    /// assert!(d >= Duration::from_millis(950) && d <= Duration::from_millis(1050));
+    /// ```
+    #[cfg(feature = "rand")]
+    fn jitter_abs(self, amount: Duration) -> Duration;
+
+    /// Returns how many frames at `fps` frames per second `self` spans.
+    ///
+    /// A zero `fps` yields zero frames.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use easyduration::{DurationExt, EasyDuration};
+    ///
+    /// assert_eq!(1.seconds().as_frames(60), 60.0);
+    /// assert_eq!(Duration::from_millis(25).as_frames(60), 1.5);
+    /// ```
+    fn as_frames(self, fps: u32) -> f64;
+
+    /// Returns how many times longer `self` is than `baseline`.
+    ///
+    /// A zero `baseline` yields `1.0` if `self` is zero as well and
+    /// [`f64::INFINITY`] otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use easyduration::{DurationExt, EasyDuration};
+    ///
+    /// assert_eq!(Duration::from_millis(1250).ratio_to(1.seconds()), 1.25);
+    /// ```
+    fn ratio_to(self, baseline: Duration) -> f64;
+
+    /// Formats [`ratio_to`](DurationExt::ratio_to) with two decimal places,
+    /// like `"1.25x"`.
+    ///
+    /// An infinite ratio renders as `"∞x"`.
+    fn ratio_string(self, baseline: Duration) -> String;
+
+    /// Returns the point in time `self` after `base`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result can't be represented as a [`SystemTime`].
+    fn after(self, base: SystemTime) -> SystemTime;
+
+    /// Returns the point in time `self` before `base`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result can't be represented as a [`SystemTime`].
+    fn before(self, base: SystemTime) -> SystemTime;
+}
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for std::time::Duration {}
+}
+
+impl DurationExt for Duration {
+    fn budget(self) -> Budget {
+        Budget::new(self)
+    }
+
+    fn deadline(self) -> Deadline {
+        Deadline::new(self)
+    }
+
+    fn keepalive(self) -> KeepAlive {
+        KeepAlive::new(self)
+    }
+
+    fn grow(self, factor: f64, cap: Duration) -> Duration {
+        let secs = self.as_secs_f64() * factor;
+        if secs.is_nan() || secs <= 0.0 {
+            return Duration::ZERO;
+        }
+        Duration::try_from_secs_f64(secs)
+            .unwrap_or(Duration::MAX)
+            .min(cap)
+    }
+
+    fn jitter_seeded(self, fraction: f64, seed: u64) -> Duration {
+        // Map the top 53 bits to a uniform value in [-1, 1).
+        let unit = (splitmix64(seed) >> 11) as f64 / (1u64 << 53) as f64 * 2.0 - 1.0;
+        jitter(self, fraction, unit)
+    }
+
+    fn is_whole(self, unit: Duration) -> bool {
+        self.as_nanos()
+            .checked_rem(unit.as_nanos())
+            .is_some_and(|rem| rem == 0)
+    }
+
+    fn truncate_subsec(self) -> Duration {
+        Duration::from_secs(self.as_secs())
+    }
+
+    fn round_subsec(self) -> Duration {
+        let round_up = self.subsec_nanos() >= 500_000_000;
+        Duration::from_secs(self.as_secs().saturating_add(round_up.into()))
+    }
+
+    #[cfg(feature = "rand")]
+    fn to(self, other: Duration) -> Duration {
+        crate::random::random_between(self.min(other), self.max(other))
+    }
+
+    #[cfg(feature = "rand")]
+    fn jitter_abs(self, amount: Duration) -> Duration {
+        crate::random::random_between(self.saturating_sub(amount), self.saturating_add(amount))
+    }
+
+    fn as_frames(self, fps: u32) -> f64 {
+        self.as_secs_f64() * f64::from(fps)
+    }
+
+    fn ratio_to(self, baseline: Duration) -> f64 {
+        if baseline.is_zero() {
+            return if self.is_zero() { 1.0 } else { f64::INFINITY };
+        }
+        self.as_secs_f64() / baseline.as_secs_f64()
+    }
+
+    fn ratio_string(self, baseline: Duration) -> String {
+        let ratio = self.ratio_to(baseline);
+        if ratio.is_infinite() {
+            "∞x".to_string()
+        } else {
+            format!("{ratio:.2}x")
+        }
+    }
+
+    fn after(self, base: SystemTime) -> SystemTime {
+        base + self
+    }
+
+    fn before(self, base: SystemTime) -> SystemTime {
+        base - self
+    }
+}
+
+/// Perturbs `d` by `unit` times `±fraction` of its length, for a `unit` in
+/// `[-1, 1]`.
+///
+/// The sign of `fraction` is ignored. The result never drops below zero and
+/// saturates at [`Duration::MAX`].
+pub(crate) fn jitter(d: Duration, fraction: f64, unit: f64) -> Duration {
+    let secs = d.as_secs_f64() * (1.0 + fraction.abs() * unit);
+    Duration::try_from_secs_f64(secs.max(0.0)).unwrap_or(Duration::MAX)
+}
+
+/// A single round of the SplitMix64 generator.
+fn splitmix64(seed: u64) -> u64 {
+    let mut z = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EasyDuration;
+
+    #[test]
+    fn test_grow() {
+        let cap = 10.seconds();
+        assert_eq!(1.seconds().grow(2.0, cap), 2.seconds());
+        assert_eq!(3.seconds().grow(1.5, cap), Duration::from_millis(4500));
+        assert_eq!(6.seconds().grow(2.0, cap), cap);
+        assert_eq!(cap.grow(2.0, cap), cap);
+
+        let mut interval = 1.seconds();
+        let steps: Vec<_> = (0..5)
+            .map(|_| {
+                interval = interval.grow(2.0, cap);
+                interval
+            })
+            .collect();
+        assert_eq!(steps, [2.seconds(), 4.seconds(), 8.seconds(), cap, cap]);
+
+        assert_eq!(Duration::MAX.grow(2.0, Duration::MAX), Duration::MAX);
+        assert_eq!(1.seconds().grow(f64::INFINITY, cap), cap);
+        assert_eq!(1.seconds().grow(-1.0, cap), Duration::ZERO);
+        assert_eq!(1.seconds().grow(f64::NAN, cap), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_jitter_seeded() {
+        let base = 10.seconds();
+        assert_eq!(base.jitter_seeded(0.1, 7), base.jitter_seeded(0.1, 7));
+        assert_ne!(base.jitter_seeded(0.1, 7), base.jitter_seeded(0.1, 8));
+
+        let samples: Vec<_> = (0..1000)
+            .map(|seed| base.jitter_seeded(0.1, seed))
+            .collect();
+        assert!(samples
+            .iter()
+            .all(|d| *d >= 9.seconds() && *d <= 11.seconds()));
+        assert!(samples.iter().any(|d| *d < base));
+        assert!(samples.iter().any(|d| *d > base));
+
+        assert_eq!(base.jitter_seeded(0.0, 7), base);
+        assert_eq!(Duration::ZERO.jitter_seeded(0.5, 7), Duration::ZERO);
+        assert_eq!(base.jitter_seeded(-0.1, 7), base.jitter_seeded(0.1, 7));
+        assert!((0..100).all(|seed| base.jitter_seeded(5.0, seed) <= 60.seconds()));
+    }
+
+    #[test]
+    fn test_is_whole() {
+        assert!(120.seconds().is_whole(1.minutes()));
+        assert!(!90.seconds().is_whole(1.minutes()));
+        assert!(3.days().is_whole(1.days()));
+        assert!(Duration::from_millis(1500).is_whole(Duration::from_millis(500)));
+        assert!(!Duration::from_millis(1500).is_whole(1.seconds()));
+        assert!(Duration::ZERO.is_whole(1.seconds()));
+
+        assert!(!1.seconds().is_whole(Duration::ZERO));
+        assert!(!Duration::ZERO.is_whole(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_truncate_round_subsec() {
+        let ms = Duration::from_millis;
+
+        assert_eq!(ms(1500).truncate_subsec(), 1.seconds());
+        assert_eq!(ms(1999).truncate_subsec(), 1.seconds());
+        assert_eq!(ms(999).truncate_subsec(), Duration::ZERO);
+
+        assert_eq!(ms(1500).round_subsec(), 2.seconds());
+        assert_eq!(ms(1499).round_subsec(), 1.seconds());
+        assert_eq!(ms(499).round_subsec(), Duration::ZERO);
+        assert_eq!(2.seconds().round_subsec(), 2.seconds());
+        assert_eq!(Duration::MAX.round_subsec(), Duration::from_secs(u64::MAX));
+    }
+
+    #[test]
+    fn test_as_frames() {
+        let ms = Duration::from_millis;
+
+        assert_eq!(1.seconds().as_frames(60), 60.0);
+        assert_eq!(2.seconds().as_frames(30), 60.0);
+        assert_eq!(ms(25).as_frames(60), 1.5);
+        assert!((ms(16).as_frames(60) - 0.96).abs() < 1e-9);
+        assert_eq!(Duration::ZERO.as_frames(60), 0.0);
+        assert_eq!(1.seconds().as_frames(0), 0.0);
+    }
+
+    #[test]
+    fn test_ratio_to() {
+        let ms = Duration::from_millis;
+        let baseline = 1.seconds();
+
+        assert_eq!(baseline.ratio_to(baseline), 1.0);
+        assert_eq!(ms(1250).ratio_to(baseline), 1.25);
+        assert_eq!(ms(500).ratio_to(baseline), 0.5);
+        assert_eq!(Duration::ZERO.ratio_to(baseline), 0.0);
+        assert_eq!(baseline.ratio_to(Duration::ZERO), f64::INFINITY);
+        assert_eq!(Duration::ZERO.ratio_to(Duration::ZERO), 1.0);
+
+        assert_eq!(baseline.ratio_string(baseline), "1.00x");
+        assert_eq!(ms(1250).ratio_string(baseline), "1.25x");
+        assert_eq!(ms(500).ratio_string(baseline), "0.50x");
+        assert_eq!(3.seconds().ratio_string(ms(1500)), "2.00x");
+        assert_eq!(ms(1).ratio_string(3.seconds()), "0.00x");
+        assert_eq!(baseline.ratio_string(Duration::ZERO), "∞x");
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_to() {
+        let (low, high) = (1.seconds(), 3.seconds());
+        for _ in 0..1000 {
+            assert!((low..=high).contains(&low.to(high)));
+            assert!((low..=high).contains(&high.to(low)));
+        }
+        assert_eq!(low.to(low), low);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_jitter_abs() {
+        let ms = Duration::from_millis;
+        let base = 1.seconds();
+        let samples: Vec<_> = (0..1000).map(|_| base.jitter_abs(ms(50))).collect();
+        assert!(samples.iter().all(|d| (ms(950)..=ms(1050)).contains(d)));
+        assert!(samples.iter().any(|d| *d < base));
+        assert!(samples.iter().any(|d| *d > base));
+
+        // Floored at zero.
+        assert!((0..1000).all(|_| ms(20).jitter_abs(ms(50)) <= ms(70)));
+        assert_eq!(base.jitter_abs(Duration::ZERO), base);
+        assert!(Duration::MAX.jitter_abs(1.seconds()) >= Duration::MAX - 1.seconds());
+    }
+}
@@ -0,0 +1,742 @@
+//! Rendering of [`std::time::Duration`] values as human readable text.
+
+use std::time::Duration;
+
+use crate::{DurationExt, Unit};
+
+/// Units used for human readable phrases, from largest to smallest.
+const PHRASE_UNITS: [(u64, &str); 6] = [
+    (60 * 60 * 24 * 365, "year"),
+    (60 * 60 * 24 * 7, "week"),
+    (60 * 60 * 24, "day"),
+    (60 * 60, "hour"),
+    (60, "minute"),
+    (1, "second"),
+];
+
+/// Units used by [`humanize`], from largest to smallest.
+const HUMANIZE_UNITS: [Unit; 8] = [
+    Unit::Years,
+    Unit::Days,
+    Unit::Hours,
+    Unit::Minutes,
+    Unit::Seconds,
+    Unit::Milliseconds,
+    Unit::Microseconds,
+    Unit::Nanoseconds,
+];
+
+/// Renders `d` as space separated components, like `"1h 2m 5s"`.
+///
+/// Zero components are omitted and a zero duration renders as `"0s"`. Days
+/// are the largest unit below years, weeks are not used. See
+/// [`humanize_with`] for more control over the output.
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{format::humanize, EasyDuration};
+///
+/// assert_eq!(humanize(3725.seconds()), "1h 2m 5s");
+/// ```
+pub fn humanize(d: Duration) -> String {
+    humanize_with(d, &HumanizeOptions::default())
+}
+
+/// Renders `d` in the compact canonical form, like `"1d2h3m4s"`.
+///
+/// This uses the same components as [`humanize`] without spaces, which makes
+/// it suitable for map keys or file names. A zero duration renders as
+/// `"0s"`. The output is accepted by [`parse()`](crate::parse()).
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{format::to_compact, parse, EasyDuration};
+///
+/// let d = 1.days() + 2.hours() + 3.minutes() + 4.seconds();
+/// assert_eq!(to_compact(d), "1d2h3m4s");
+/// assert_eq!(parse(&to_compact(d)).unwrap(), d);
+/// ```
+pub fn to_compact(d: Duration) -> String {
+    let parts = components(d.as_nanos(), &HUMANIZE_UNITS, None);
+    if parts.is_empty() {
+        return "0s".to_string();
+    }
+    parts
+        .into_iter()
+        .map(|(count, unit)| format!("{count}{unit}"))
+        .collect()
+}
+
+/// Options for [`humanize_with`].
+///
+/// The default options render all components with short unit names, like
+/// [`humanize`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HumanizeOptions {
+    /// The maximum number of components to render, or `None` for no limit.
+    pub max_components: Option<usize>,
+    /// Render long unit names like `"2 hours"` instead of `"2h"`.
+    pub long_names: bool,
+    /// The smallest unit to render. Weeks are not used, so
+    /// [`Unit::Weeks`] behaves like [`Unit::Days`].
+    pub smallest_unit: Unit,
+    /// Round the last rendered component to the nearest whole value instead
+    /// of truncating the parts that are not shown.
+    pub round_tail: bool,
+}
+
+impl Default for HumanizeOptions {
+    fn default() -> Self {
+        Self {
+            max_components: None,
+            long_names: false,
+            smallest_unit: Unit::Nanoseconds,
+            round_tail: false,
+        }
+    }
+}
+
+/// Renders `d` as space separated components according to `opts`.
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{
+///     format::{humanize_with, HumanizeOptions},
+///     EasyDuration, Unit,
+/// };
+///
+/// let opts = HumanizeOptions {
+///     max_components: Some(2),
+///     long_names: true,
+///     round_tail: true,
+///     ..Default::default()
+/// };
+/// assert_eq!(humanize_with(3725.seconds(), &opts), "1 hour 2 minutes");
+/// ```
+pub fn humanize_with(d: Duration, opts: &HumanizeOptions) -> String {
+    let smallest_unit = match opts.smallest_unit {
+        Unit::Weeks => Unit::Days,
+        unit => unit,
+    };
+    let units: Vec<Unit> = HUMANIZE_UNITS
+        .into_iter()
+        .filter(|unit| *unit >= smallest_unit)
+        .collect();
+
+    let mut nanos = d.as_nanos();
+    if opts.round_tail {
+        let tail = match components(nanos, &units, opts.max_components).last() {
+            Some((_, unit)) => *unit,
+            None => units[units.len() - 1],
+        };
+        let tail_nanos = tail.duration().as_nanos();
+        nanos = (nanos + tail_nanos / 2) / tail_nanos * tail_nanos;
+    }
+
+    let parts = components(nanos, &units, opts.max_components);
+    if parts.is_empty() {
+        return render_component(0, smallest_unit.max(Unit::Seconds), opts.long_names);
+    }
+    parts
+        .into_iter()
+        .map(|(count, unit)| render_component(count, unit, opts.long_names))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Splits `nanos` into non-zero `(count, unit)` components, largest first.
+fn components(mut nanos: u128, units: &[Unit], max: Option<usize>) -> Vec<(u128, Unit)> {
+    let mut parts = Vec::new();
+    for unit in units {
+        let unit_nanos = unit.duration().as_nanos();
+        let count = nanos / unit_nanos;
+        if count > 0 {
+            parts.push((count, *unit));
+            nanos %= unit_nanos;
+        }
+    }
+    parts.truncate(max.unwrap_or(usize::MAX));
+    parts
+}
+
+fn render_component(count: u128, unit: Unit, long_name: bool) -> String {
+    if !long_name {
+        return format!("{count}{unit}");
+    }
+    let plural = if count == 1 { "" } else { "s" };
+    format!("{count} {}{plural}", unit_name(unit))
+}
+
+/// Returns the singular long name of `unit`, like `"hour"`.
+fn unit_name(unit: Unit) -> &'static str {
+    match unit {
+        Unit::Nanoseconds => "nanosecond",
+        Unit::Microseconds => "microsecond",
+        Unit::Milliseconds => "millisecond",
+        Unit::Seconds => "second",
+        Unit::Minutes => "minute",
+        Unit::Hours => "hour",
+        Unit::Days => "day",
+        Unit::Weeks => "week",
+        Unit::Years => "year",
+    }
+}
+
+/// Renders `d` as a count of a single `unit`, like `"1.5 hours"`.
+///
+/// The count is rounded to at most two decimal places. Only a count of
+/// exactly `1` uses the singular unit name.
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{format::in_unit_string, EasyDuration, Unit};
+///
+/// assert_eq!(in_unit_string(90.minutes(), Unit::Hours), "1.5 hours");
+/// assert_eq!(in_unit_string(1.days(), Unit::Days), "1 day");
+/// ```
+pub fn in_unit_string(d: Duration, unit: Unit) -> String {
+    let value = d.as_nanos() as f64 / unit.duration().as_nanos() as f64;
+    let count = trim_fraction(format!("{value:.2}"));
+    let plural = if count == "1" { "" } else { "s" };
+    format!("{count} {}{plural}", unit_name(unit))
+}
+
+/// Renders `d` with an automatically chosen SI unit, like `"1.5 µs"` or
+/// `"500 ns"`.
+///
+/// Durations under one second use `ns`, `µs` or `ms` with up to three
+/// significant figures. Longer durations, and those rounding up to a full
+/// second, fall back to [`humanize`].
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use easyduration::format::humanize_si;
+///
+/// assert_eq!(humanize_si(Duration::from_nanos(1500)), "1.5 µs");
+/// assert_eq!(humanize_si(Duration::from_nanos(500)), "500 ns");
+/// ```
+pub fn humanize_si(d: Duration) -> String {
+    const SI_UNITS: [(f64, &str); 3] = [(1.0, "ns"), (1e3, "µs"), (1e6, "ms")];
+
+    if d.as_secs() > 0 {
+        return humanize(d);
+    }
+    let nanos = d.subsec_nanos() as f64;
+    let mut index = SI_UNITS
+        .iter()
+        .rposition(|(scale, _)| nanos >= *scale)
+        .unwrap_or(0);
+    loop {
+        let (scale, name) = SI_UNITS[index];
+        let value = significant(nanos / scale, 3);
+        // Rounding may carry over into the next unit, like 999.9 µs.
+        if value >= 1000.0 {
+            index += 1;
+            if index == SI_UNITS.len() {
+                return humanize(Duration::from_secs(1));
+            }
+            continue;
+        }
+        return format!("{} {name}", trim_fraction(format!("{value:.2}")));
+    }
+}
+
+/// Describes `d` as a repeating interval, like `"every 2 hours"`.
+///
+/// The largest unit from seconds up to years that divides `d` exactly is
+/// used, so 90 seconds render as `"every 90 seconds"`. Durations that aren't
+/// a whole number of seconds fall back to [`humanize`], like
+/// `"every 1s 500ms"`.
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{format::describe_interval, EasyDuration};
+///
+/// assert_eq!(describe_interval(1.seconds()), "every second");
+/// assert_eq!(describe_interval(30.minutes()), "every 30 minutes");
+/// ```
+pub fn describe_interval(d: Duration) -> String {
+    let unit = PHRASE_UNITS
+        .iter()
+        .find(|(unit_secs, _)| !d.is_zero() && d.is_whole(Duration::from_secs(*unit_secs)));
+    match unit {
+        Some((unit_secs, name)) => match d.as_secs() / unit_secs {
+            1 => format!("every {name}"),
+            count => format!("every {count} {name}s"),
+        },
+        None => format!("every {}", humanize(d)),
+    }
+}
+
+/// Rounds `value` to `digits` significant figures.
+fn significant(value: f64, digits: i32) -> f64 {
+    if value == 0.0 {
+        return 0.0;
+    }
+    let magnitude = 10f64.powi(digits - 1 - value.abs().log10().floor() as i32);
+    (value * magnitude).round() / magnitude
+}
+
+/// Strips trailing zeros and a trailing decimal point from a formatted number.
+fn trim_fraction(s: String) -> String {
+    if s.contains('.') {
+        s.trim_end_matches('0').trim_end_matches('.').to_string()
+    } else {
+        s
+    }
+}
+
+/// Renders `d` as a relative phrase like `"3 minutes ago"` or `"in 2 hours"`.
+///
+/// `past` selects between the `"... ago"` and `"in ..."` forms. The largest
+/// unit that fits into `d` is used and the count is rounded to the nearest
+/// whole number, moving on to the next larger unit if rounding reaches it.
+/// Durations under one second render as `"just now"` or `"in a moment"`.
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{format::relative_phrase, EasyDuration};
+///
+/// assert_eq!(relative_phrase(3.minutes(), true), "3 minutes ago");
+/// assert_eq!(relative_phrase(2.hours(), false), "in 2 hours");
+/// ```
+pub fn relative_phrase(d: Duration, past: bool) -> String {
+    let secs = d.as_secs_f64();
+    let Some(mut index) = PHRASE_UNITS
+        .iter()
+        .position(|(unit, _)| d.as_secs() >= *unit)
+    else {
+        return if past { "just now" } else { "in a moment" }.to_string();
+    };
+
+    let count_of = |index: usize| (secs / PHRASE_UNITS[index].0 as f64).round() as u64;
+    let mut count = count_of(index);
+    // Rounding up can reach the next larger unit, like 59.5 minutes.
+    while index > 0 && count.saturating_mul(PHRASE_UNITS[index].0) >= PHRASE_UNITS[index - 1].0 {
+        index -= 1;
+        count = count_of(index);
+    }
+    let name = PHRASE_UNITS[index].1;
+    let plural = if count == 1 { "" } else { "s" };
+    if past {
+        format!("{count} {name}{plural} ago")
+    } else {
+        format!("in {count} {name}{plural}")
+    }
+}
+
+/// Renders `d` as bare decimal seconds, like `"1.5"`, as used by the
+/// Prometheus exposition format.
+///
+/// The value is exact to the nanosecond, without trailing zeros and never in
+/// exponent notation.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use easyduration::format::to_prom_seconds;
+///
+/// assert_eq!(to_prom_seconds(Duration::from_millis(1500)), "1.5");
+/// assert_eq!(to_prom_seconds(Duration::from_secs(2)), "2");
+/// ```
+pub fn to_prom_seconds(d: Duration) -> String {
+    let secs = d.as_secs();
+    let nanos = d.subsec_nanos();
+    if nanos == 0 {
+        return secs.to_string();
+    }
+    let fraction = format!("{nanos:09}");
+    format!("{secs}.{}", fraction.trim_end_matches('0'))
+}
+
+/// Renders `d` as an ISO 8601 duration, like `"PT1H30M"`.
+///
+/// Only hours, minutes and seconds are used, since days are not always 24
+/// hours long in ISO 8601. Seconds are exact to the nanosecond without
+/// trailing zeros and a zero duration renders as `"PT0S"`. The output is
+/// accepted by [`parse_iso8601`](crate::parse::parse_iso8601).
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use easyduration::{format::to_iso8601, EasyDuration};
+///
+/// assert_eq!(to_iso8601(90.minutes()), "PT1H30M");
+/// assert_eq!(to_iso8601(Duration::from_millis(1500)), "PT1.5S");
+/// ```
+pub fn to_iso8601(d: Duration) -> String {
+    let (hours, minutes, seconds) = crate::convert::hms(d);
+    let mut out = "PT".to_string();
+    if hours > 0 {
+        out += &format!("{hours}H");
+    }
+    if minutes > 0 {
+        out += &format!("{minutes}M");
+    }
+    if seconds > 0 || d.subsec_nanos() > 0 || out.len() == 2 {
+        let fraction = Duration::new(u64::from(seconds), d.subsec_nanos());
+        out += &format!("{}S", to_prom_seconds(fraction));
+    }
+    out
+}
+
+/// Renders `remaining` as a `"mm:ss"` countdown, like `"02:05"`.
+///
+/// Sub-second parts are discarded. Minutes are not wrapped into hours, so
+/// durations of an hour or more render as `"60:00"` and beyond, see
+/// [`countdown_hmmss`] for a variant with hours.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use easyduration::{format::countdown_mmss, EasyDuration};
+///
+/// assert_eq!(countdown_mmss(125.seconds()), "02:05");
+/// assert_eq!(countdown_mmss(Duration::ZERO), "00:00");
+/// ```
+pub fn countdown_mmss(remaining: Duration) -> String {
+    let secs = remaining.as_secs();
+    format!("{:02}:{:02}", secs / 60, secs % 60)
+}
+
+/// Renders `remaining` as a countdown like `"02:05"`, adding hours when
+/// needed, like `"1:02:05"`.
+///
+/// Sub-second parts are discarded.
+pub fn countdown_hmmss(remaining: Duration) -> String {
+    let (hours, minutes, seconds) = crate::convert::hms(remaining);
+    if hours == 0 {
+        format!("{minutes:02}:{seconds:02}")
+    } else {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    }
+}
+
+/// Renders `d` according to a strftime-like `pattern`.
+///
+/// Supported tokens are:
+///
+/// * `%H`: whole hours, zero-padded to two digits. Hours are not wrapped into
+///   days.
+/// * `%M`: minutes of the hour, zero-padded to two digits.
+/// * `%S`: seconds of the minute, zero-padded to two digits.
+/// * `%1f` to `%9f`: the sub-second part with the given number of digits,
+///   truncated. `%3f` renders milliseconds.
+/// * `%%`: a literal `%`.
+///
+/// Unknown tokens and all other text are copied to the output literally.
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{format::format_pattern, EasyDuration};
+///
+/// assert_eq!(format_pattern(3725.seconds(), "%H:%M:%S"), "01:02:05");
+/// ```
+pub fn format_pattern(d: Duration, pattern: &str) -> String {
+    let (hours, minutes, seconds) = crate::convert::hms(d);
+    let mut out = String::with_capacity(pattern.len());
+    let mut rest = pattern;
+    while let Some(index) = rest.find('%') {
+        out.push_str(&rest[..index]);
+        rest = &rest[index..];
+
+        let mut chars = rest.chars().skip(1);
+        let (rendered, len) = match (chars.next(), chars.next()) {
+            (Some('H'), _) => (format!("{hours:02}"), 2),
+            (Some('M'), _) => (format!("{minutes:02}"), 2),
+            (Some('S'), _) => (format!("{seconds:02}"), 2),
+            (Some('%'), _) => ("%".to_string(), 2),
+            (Some(digit @ '1'..='9'), Some('f')) => {
+                let digits = digit as usize - '0' as usize;
+                let fraction = format!("{:09}", d.subsec_nanos());
+                (fraction[..digits].to_string(), 3)
+            }
+            _ => ("%".to_string(), 1),
+        };
+        out.push_str(&rendered);
+        rest = &rest[len..];
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EasyDuration;
+
+    #[test]
+    fn test_relative_phrase() {
+        assert_eq!(relative_phrase(1.seconds(), true), "1 second ago");
+        assert_eq!(relative_phrase(45.seconds(), true), "45 seconds ago");
+        assert_eq!(relative_phrase(3.minutes(), true), "3 minutes ago");
+        assert_eq!(relative_phrase(90.minutes(), true), "2 hours ago");
+        assert_eq!(relative_phrase(1.days(), true), "1 day ago");
+        assert_eq!(relative_phrase(3.years(), true), "3 years ago");
+
+        assert_eq!(relative_phrase(1.seconds(), false), "in 1 second");
+        assert_eq!(relative_phrase(2.hours(), false), "in 2 hours");
+        assert_eq!(relative_phrase(14.days(), false), "in 2 weeks");
+
+        // Rounding carries into the next larger unit.
+        assert_eq!(relative_phrase(3599.seconds(), true), "1 hour ago");
+        assert_eq!(relative_phrase(86_399.seconds(), true), "1 day ago");
+        assert_eq!(relative_phrase(6.days() + 23.hours(), true), "1 week ago");
+        assert_eq!(
+            relative_phrase(Duration::from_millis(59_600), false),
+            "in 1 minute"
+        );
+        assert_eq!(relative_phrase(3569.seconds(), true), "59 minutes ago");
+
+        assert_eq!(relative_phrase(Duration::ZERO, true), "just now");
+        assert_eq!(
+            relative_phrase(Duration::from_millis(999), true),
+            "just now"
+        );
+        assert_eq!(
+            relative_phrase(Duration::from_millis(500), false),
+            "in a moment"
+        );
+    }
+
+    #[test]
+    fn test_to_prom_seconds() {
+        assert_eq!(to_prom_seconds(Duration::ZERO), "0");
+        assert_eq!(to_prom_seconds(2.seconds()), "2");
+        assert_eq!(to_prom_seconds(1.hours()), "3600");
+        assert_eq!(to_prom_seconds(Duration::from_millis(1500)), "1.5");
+        assert_eq!(to_prom_seconds(Duration::from_millis(250)), "0.25");
+        assert_eq!(to_prom_seconds(Duration::from_nanos(1)), "0.000000001");
+        assert_eq!(
+            to_prom_seconds(Duration::MAX),
+            format!("{}.999999999", u64::MAX)
+        );
+    }
+
+    #[test]
+    fn test_humanize() {
+        assert_eq!(humanize(Duration::ZERO), "0s");
+        assert_eq!(humanize(3725.seconds()), "1h 2m 5s");
+        assert_eq!(humanize(1.hours()), "1h");
+        assert_eq!(humanize(Duration::from_millis(1500)), "1s 500ms");
+        assert_eq!(humanize(Duration::from_nanos(1_001)), "1us 1ns");
+        assert_eq!(humanize(400.days() + 1.seconds()), "1y 35d 1s");
+    }
+
+    #[test]
+    fn test_humanize_si() {
+        let ns = Duration::from_nanos;
+
+        assert_eq!(humanize_si(Duration::ZERO), "0 ns");
+        assert_eq!(humanize_si(ns(500)), "500 ns");
+        assert_eq!(humanize_si(ns(1500)), "1.5 µs");
+        assert_eq!(humanize_si(ns(12_345)), "12.3 µs");
+        assert_eq!(humanize_si(ns(123_456)), "123 µs");
+        assert_eq!(humanize_si(ns(999_999)), "1 ms");
+        assert_eq!(humanize_si(Duration::from_millis(250)), "250 ms");
+        assert_eq!(humanize_si(ns(999_999_999)), "1s");
+        assert_eq!(humanize_si(ns(999_499_999)), "999 ms");
+        assert_eq!(humanize_si(Duration::from_millis(1500)), "1s 500ms");
+        assert_eq!(humanize_si(3725.seconds()), "1h 2m 5s");
+    }
+
+    #[test]
+    fn test_describe_interval() {
+        assert_eq!(describe_interval(1.seconds()), "every second");
+        assert_eq!(describe_interval(45.seconds()), "every 45 seconds");
+        assert_eq!(describe_interval(90.seconds()), "every 90 seconds");
+        assert_eq!(describe_interval(30.minutes()), "every 30 minutes");
+        assert_eq!(describe_interval(2.hours()), "every 2 hours");
+        assert_eq!(describe_interval(1.days()), "every day");
+        assert_eq!(describe_interval(14.days()), "every 2 weeks");
+
+        assert_eq!(
+            describe_interval(Duration::from_millis(1500)),
+            "every 1s 500ms"
+        );
+        assert_eq!(describe_interval(Duration::from_millis(250)), "every 250ms");
+        assert_eq!(describe_interval(Duration::ZERO), "every 0s");
+    }
+
+    #[test]
+    fn test_humanize_with() {
+        let d = 1.hours() + 2.minutes() + 5.seconds();
+        let opts = |f: fn(&mut HumanizeOptions)| {
+            let mut opts = HumanizeOptions::default();
+            f(&mut opts);
+            opts
+        };
+
+        assert_eq!(humanize_with(d, &HumanizeOptions::default()), humanize(d));
+
+        // max_components truncates.
+        let two = opts(|o| o.max_components = Some(2));
+        assert_eq!(humanize_with(d, &two), "1h 2m");
+        assert_eq!(humanize_with(1.hours() + 5.seconds(), &two), "1h 5s");
+
+        // long_names.
+        let long = opts(|o| o.long_names = true);
+        assert_eq!(humanize_with(d, &long), "1 hour 2 minutes 5 seconds");
+        assert_eq!(humanize_with(Duration::ZERO, &long), "0 seconds");
+
+        // smallest_unit.
+        let secs = opts(|o| o.smallest_unit = Unit::Seconds);
+        assert_eq!(humanize_with(Duration::from_millis(1500), &secs), "1s");
+        assert_eq!(humanize_with(Duration::from_millis(500), &secs), "0s");
+        let minutes = opts(|o| o.smallest_unit = Unit::Minutes);
+        assert_eq!(humanize_with(d, &minutes), "1h 2m");
+        assert_eq!(humanize_with(30.seconds(), &minutes), "0m");
+        let weeks = opts(|o| o.smallest_unit = Unit::Weeks);
+        assert_eq!(humanize_with(Duration::ZERO, &weeks), "0d");
+        assert_eq!(humanize_with(10.days() + 5.hours(), &weeks), "10d");
+
+        // round_tail.
+        let rounded = opts(|o| {
+            o.max_components = Some(2);
+            o.round_tail = true;
+        });
+        assert_eq!(
+            humanize_with(1.hours() + 2.minutes() + 30.seconds(), &rounded),
+            "1h 3m"
+        );
+        assert_eq!(
+            humanize_with(1.hours() + 59.minutes() + 40.seconds(), &rounded),
+            "2h"
+        );
+        let rounded_secs = opts(|o| {
+            o.smallest_unit = Unit::Seconds;
+            o.round_tail = true;
+        });
+        assert_eq!(
+            humanize_with(Duration::from_millis(1500), &rounded_secs),
+            "2s"
+        );
+        assert_eq!(
+            humanize_with(Duration::from_millis(500), &rounded_secs),
+            "1s"
+        );
+    }
+
+    #[test]
+    fn test_format_pattern() {
+        let d = 3725.seconds() + Duration::from_millis(42);
+
+        assert_eq!(format_pattern(d, "%H:%M:%S"), "01:02:05");
+        assert_eq!(format_pattern(d, "%H:%M:%S.%3f"), "01:02:05.042");
+        assert_eq!(format_pattern(d, "%1f %6f %9f"), "0 042000 042000000");
+        assert_eq!(
+            format_pattern(d, "took %M min %S sec"),
+            "took 02 min 05 sec"
+        );
+        assert_eq!(format_pattern(100.hours(), "%Hh"), "100h");
+        assert_eq!(format_pattern(Duration::ZERO, "%H:%M:%S"), "00:00:00");
+
+        // Unknown tokens are passed through.
+        assert_eq!(format_pattern(d, "%Y %0f %3x 100%% %"), "%Y %0f %3x 100% %");
+        assert_eq!(format_pattern(d, "%é"), "%é");
+        assert_eq!(format_pattern(d, ""), "");
+    }
+
+    #[test]
+    fn test_in_unit_string() {
+        assert_eq!(in_unit_string(90.minutes(), Unit::Hours), "1.5 hours");
+        assert_eq!(in_unit_string(1.hours(), Unit::Hours), "1 hour");
+        assert_eq!(in_unit_string(2.hours(), Unit::Hours), "2 hours");
+        assert_eq!(in_unit_string(90.minutes(), Unit::Minutes), "90 minutes");
+        assert_eq!(
+            in_unit_string(1.seconds(), Unit::Milliseconds),
+            "1000 milliseconds"
+        );
+        assert_eq!(
+            in_unit_string(Duration::from_nanos(1), Unit::Nanoseconds),
+            "1 nanosecond"
+        );
+        assert_eq!(in_unit_string(14.days(), Unit::Weeks), "2 weeks");
+        assert_eq!(in_unit_string(Duration::ZERO, Unit::Seconds), "0 seconds");
+
+        // Counts are rounded to two decimal places.
+        assert_eq!(in_unit_string(20.minutes(), Unit::Hours), "0.33 hours");
+        assert_eq!(
+            in_unit_string(Duration::from_millis(1004), Unit::Seconds),
+            "1 second"
+        );
+        assert_eq!(
+            in_unit_string(Duration::from_millis(1010), Unit::Seconds),
+            "1.01 seconds"
+        );
+        assert_eq!(
+            in_unit_string(Duration::from_millis(999), Unit::Seconds),
+            "1 second"
+        );
+        assert_eq!(
+            in_unit_string(Duration::from_millis(990), Unit::Seconds),
+            "0.99 seconds"
+        );
+    }
+
+    #[test]
+    fn test_to_compact() {
+        assert_eq!(
+            to_compact(1.days() + 2.hours() + 3.minutes() + 4.seconds()),
+            "1d2h3m4s"
+        );
+        assert_eq!(to_compact(Duration::ZERO), "0s");
+        assert_eq!(to_compact(1.hours() + 4.seconds()), "1h4s");
+        assert_eq!(to_compact(Duration::from_nanos(1_500_001)), "1ms500us1ns");
+
+        let mut durations = vec![Duration::MAX, Duration::from_nanos(1), 400.days()];
+        durations.extend((0..200u64).map(|i| Duration::from_nanos(i.pow(7) + i)));
+        for d in durations {
+            assert_eq!(crate::parse(&to_compact(d)), Ok(d), "{d:?}");
+        }
+    }
+
+    #[test]
+    fn test_countdown_mmss() {
+        assert_eq!(countdown_mmss(125.seconds()), "02:05");
+        assert_eq!(countdown_mmss(Duration::ZERO), "00:00");
+        assert_eq!(countdown_mmss(Duration::from_millis(999)), "00:00");
+        assert_eq!(countdown_mmss(Duration::from_millis(59_999)), "00:59");
+        assert_eq!(countdown_mmss(1.hours() + 5.seconds()), "60:05");
+        assert_eq!(countdown_mmss(150.minutes()), "150:00");
+
+        assert_eq!(countdown_hmmss(125.seconds()), "02:05");
+        assert_eq!(countdown_hmmss(Duration::ZERO), "00:00");
+        assert_eq!(countdown_hmmss(3725.seconds()), "1:02:05");
+        assert_eq!(countdown_hmmss(100.hours()), "100:00:00");
+    }
+
+    #[test]
+    fn test_to_iso8601() {
+        assert_eq!(to_iso8601(90.minutes()), "PT1H30M");
+        assert_eq!(to_iso8601(Duration::ZERO), "PT0S");
+        assert_eq!(to_iso8601(3725.seconds()), "PT1H2M5S");
+        assert_eq!(to_iso8601(2.days()), "PT48H");
+        assert_eq!(to_iso8601(Duration::from_millis(1500)), "PT1.5S");
+        assert_eq!(to_iso8601(Duration::from_nanos(1)), "PT0.000000001S");
+        assert_eq!(
+            to_iso8601(1.hours() + Duration::from_millis(1)),
+            "PT1H0.001S"
+        );
+
+        for d in [
+            Duration::MAX,
+            1.days() + 5.seconds(),
+            Duration::from_micros(1_234_567),
+        ] {
+            assert_eq!(crate::parse::parse_iso8601(&to_iso8601(d)), Ok(d));
+        }
+    }
+}
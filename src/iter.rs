@@ -0,0 +1,91 @@
+//! Iterators producing sequences of [`std::time::Duration`] values.
+
+use std::time::Duration;
+
+/// Returns an iterator that counts down from `total` to zero in steps of
+/// `tick`.
+///
+/// The first item is `total` and the last item is always exactly
+/// [`Duration::ZERO`]. Subtraction saturates, so a `tick` that does not evenly
+/// divide `total` still ends at zero. A zero `tick` jumps straight to zero.
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{iter::countdown, EasyDuration};
+///
+/// let steps: Vec<_> = countdown(5.seconds(), 2.seconds()).collect();
+/// assert_eq!(steps, [5.seconds(), 3.seconds(), 1.seconds(), 0.seconds()]);
+/// ```
+pub fn countdown(total: Duration, tick: Duration) -> impl Iterator<Item = Duration> {
+    std::iter::successors(Some(total), move |remaining| {
+        if remaining.is_zero() {
+            None
+        } else if tick.is_zero() {
+            Some(Duration::ZERO)
+        } else {
+            Some(remaining.saturating_sub(tick))
+        }
+    })
+}
+
+/// Returns an infinite iterator alternating between `(true, on)` and
+/// `(false, off)`, starting with the on phase.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use easyduration::iter::pulse;
+///
+/// let (on, off) = (Duration::from_millis(200), Duration::from_millis(800));
+/// let phases: Vec<_> = pulse(on, off).take(3).collect();
+/// assert_eq!(phases, [(true, on), (false, off), (true, on)]);
+/// ```
+pub fn pulse(on: Duration, off: Duration) -> impl Iterator<Item = (bool, Duration)> {
+    [(true, on), (false, off)].into_iter().cycle()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EasyDuration;
+
+    #[test]
+    fn test_countdown() {
+        let steps: Vec<_> = countdown(5.seconds(), 2.seconds()).collect();
+        assert_eq!(steps, [5.seconds(), 3.seconds(), 1.seconds(), 0.seconds()]);
+
+        let steps: Vec<_> = countdown(4.seconds(), 2.seconds()).collect();
+        assert_eq!(steps, [4.seconds(), 2.seconds(), 0.seconds()]);
+
+        let steps: Vec<_> = countdown(Duration::ZERO, 1.seconds()).collect();
+        assert_eq!(steps, [Duration::ZERO]);
+
+        let steps: Vec<_> = countdown(3.seconds(), Duration::ZERO).collect();
+        assert_eq!(steps, [3.seconds(), Duration::ZERO]);
+    }
+
+    #[test]
+    fn test_pulse() {
+        let (on, off) = (1.seconds(), 3.seconds());
+        let phases: Vec<_> = pulse(on, off).take(5).collect();
+        assert_eq!(
+            phases,
+            [
+                (true, on),
+                (false, off),
+                (true, on),
+                (false, off),
+                (true, on)
+            ]
+        );
+
+        let total: Duration = pulse(on, off).take(1000).map(|(_, d)| d).sum();
+        assert_eq!(total, 2000.seconds());
+        assert_eq!(
+            pulse(on, off).take(1000).filter(|(lit, _)| *lit).count(),
+            500
+        );
+    }
+}
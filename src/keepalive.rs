@@ -0,0 +1,100 @@
+//! An idle timeout that is extended by activity.
+
+use std::time::{Duration, Instant};
+
+use crate::clock::{Clock, SystemClock};
+
+/// Expires after a period of inactivity.
+///
+/// Each [`touch`](KeepAlive::touch) restarts the timeout. Like
+/// [`Deadline`](crate::deadline::Deadline) it reads the current time from a
+/// [`Clock`], which defaults to the [`SystemClock`].
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{DurationExt, EasyDuration};
+///
+/// let mut keepalive = 30.seconds().keepalive();
+/// keepalive.touch();
+/// assert!(!keepalive.is_expired());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KeepAlive<C: Clock = SystemClock> {
+    timeout: Duration,
+    last_activity: Instant,
+    clock: C,
+}
+
+impl KeepAlive {
+    /// Creates a keepalive that expires `timeout` from now unless touched.
+    pub fn new(timeout: Duration) -> Self {
+        Self::with_clock(timeout, SystemClock)
+    }
+}
+
+impl<C: Clock> KeepAlive<C> {
+    /// Creates a keepalive that expires `timeout` from the current time of
+    /// `clock` unless touched.
+    pub fn with_clock(timeout: Duration, clock: C) -> Self {
+        Self {
+            timeout,
+            last_activity: clock.now(),
+            clock,
+        }
+    }
+
+    /// Records activity, restarting the timeout from now.
+    pub fn touch(&mut self) {
+        self.last_activity = self.clock.now();
+    }
+
+    /// Returns `true` if no activity was recorded within the timeout.
+    pub fn is_expired(&self) -> bool {
+        self.remaining().is_zero()
+    }
+
+    /// Returns the time left until the keepalive expires, or zero if it
+    /// already has.
+    pub fn remaining(&self) -> Duration {
+        let idle = self
+            .clock
+            .now()
+            .saturating_duration_since(self.last_activity);
+        self.timeout.saturating_sub(idle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{clock::MockClock, DurationExt, EasyDuration};
+
+    #[test]
+    fn test_keepalive() {
+        let clock = MockClock::new();
+        let mut keepalive = KeepAlive::with_clock(30.seconds(), clock.clone());
+        assert_eq!(keepalive.remaining(), 30.seconds());
+
+        // Touching before expiry extends it.
+        clock.advance(20.seconds());
+        keepalive.touch();
+        clock.advance(20.seconds());
+        assert!(!keepalive.is_expired());
+        assert_eq!(keepalive.remaining(), 10.seconds());
+
+        // Without touches it expires after the window.
+        clock.advance(10.seconds());
+        assert!(keepalive.is_expired());
+        clock.advance(1.hours());
+        assert!(keepalive.is_expired());
+        assert_eq!(keepalive.remaining(), Duration::ZERO);
+
+        // Touching revives an expired keepalive.
+        keepalive.touch();
+        assert!(!keepalive.is_expired());
+
+        assert!(!30.seconds().keepalive().is_expired());
+        assert!(Duration::ZERO.keepalive().is_expired());
+    }
+}
@@ -0,0 +1,270 @@
+//! Classification of latencies and timeouts derived from them.
+
+use std::{collections::VecDeque, time::Duration};
+
+use crate::{
+    stats::{percentile_sorted, sort_durations},
+    DurationExt,
+};
+
+/// A coarse latency class, as assigned by [`classify`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LatencyClass {
+    /// Below the normal threshold.
+    Fast,
+    /// At least the normal threshold, but below the slow one.
+    Normal,
+    /// At least the slow threshold, but below the critical one.
+    Slow,
+    /// At least the critical threshold.
+    Critical,
+}
+
+/// The lower bounds of the latency classes above [`LatencyClass::Fast`].
+///
+/// Each bound is inclusive, so a latency exactly at `slow` is
+/// [`LatencyClass::Slow`]. The defaults are 100ms, 500ms and 2s.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LatencyThresholds {
+    normal: Duration,
+    slow: Duration,
+    critical: Duration,
+}
+
+impl LatencyThresholds {
+    /// Creates thresholds from the lower bounds of the normal, slow and
+    /// critical classes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bounds are not in ascending order.
+    pub fn new(normal: Duration, slow: Duration, critical: Duration) -> Self {
+        assert!(
+            normal <= slow && slow <= critical,
+            "latency thresholds must be in ascending order"
+        );
+        Self {
+            normal,
+            slow,
+            critical,
+        }
+    }
+}
+
+impl Default for LatencyThresholds {
+    fn default() -> Self {
+        Self::new(
+            Duration::from_millis(100),
+            Duration::from_millis(500),
+            Duration::from_secs(2),
+        )
+    }
+}
+
+/// Returns the [`LatencyClass`] of `d`.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use easyduration::latency::{classify, LatencyClass, LatencyThresholds};
+///
+/// let thresholds = LatencyThresholds::default();
+/// assert_eq!(classify(Duration::from_millis(20), &thresholds), LatencyClass::Fast);
+/// assert_eq!(classify(Duration::from_millis(750), &thresholds), LatencyClass::Slow);
+/// ```
+pub fn classify(d: Duration, thresholds: &LatencyThresholds) -> LatencyClass {
+    if d >= thresholds.critical {
+        LatencyClass::Critical
+    } else if d >= thresholds.slow {
+        LatencyClass::Slow
+    } else if d >= thresholds.normal {
+        LatencyClass::Normal
+    } else {
+        LatencyClass::Fast
+    }
+}
+
+/// An adaptive timeout based on recently observed latencies.
+///
+/// The timeout is a percentile of the last observed latencies times a
+/// multiplier, clamped to `[min, max]`. By default the 99th percentile of the
+/// last 100 observations is doubled. Without any observations the timeout is
+/// `max`.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use easyduration::{latency::TimeoutController, EasyDuration};
+///
+/// let mut controller = TimeoutController::new(Duration::from_millis(10), 5.seconds());
+/// for _ in 0..100 {
+///     controller.observe(Duration::from_millis(200));
+/// }
+/// assert_eq!(controller.current_timeout(), Duration::from_millis(400));
+/// ```
+#[derive(Clone, Debug)]
+pub struct TimeoutController {
+    samples: VecDeque<Duration>,
+    window: usize,
+    percentile: f64,
+    multiplier: f64,
+    min: Duration,
+    max: Duration,
+}
+
+impl TimeoutController {
+    /// Creates a controller whose timeout stays within `[min, max]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` is greater than `max`.
+    pub fn new(min: Duration, max: Duration) -> Self {
+        assert!(min <= max, "minimum timeout must not exceed maximum");
+        Self {
+            samples: VecDeque::new(),
+            window: 100,
+            percentile: 99.0,
+            multiplier: 2.0,
+            min,
+            max,
+        }
+    }
+
+    /// Sets the number of most recent observations to consider.
+    ///
+    /// If more latencies have been observed already, only the most recent
+    /// `window` of them are kept.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero.
+    pub fn window(mut self, window: usize) -> Self {
+        assert!(window != 0, "window must not be zero");
+        self.window = window;
+        let excess = self.samples.len().saturating_sub(window);
+        self.samples.drain(..excess);
+        self
+    }
+
+    /// Sets the percentile of the observed latencies to base the timeout on.
+    pub fn percentile(mut self, p: f64) -> Self {
+        self.percentile = p;
+        self
+    }
+
+    /// Sets the factor the percentile is multiplied by.
+    pub fn multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    /// Records an observed latency, evicting the oldest one if the window is
+    /// full.
+    pub fn observe(&mut self, latency: Duration) {
+        if self.samples.len() == self.window {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(latency);
+    }
+
+    /// Returns the timeout for the next request.
+    pub fn current_timeout(&self) -> Duration {
+        let mut samples: Vec<_> = self.samples.iter().copied().collect();
+        sort_durations(&mut samples);
+        match percentile_sorted(&samples, self.percentile) {
+            Some(p) => p.grow(self.multiplier, self.max).max(self.min),
+            None => self.max,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EasyDuration;
+
+    #[test]
+    fn test_classify() {
+        let ms = Duration::from_millis;
+        let thresholds = LatencyThresholds::default();
+        let class = |d| classify(d, &thresholds);
+
+        assert_eq!(class(Duration::ZERO), LatencyClass::Fast);
+        assert_eq!(class(ms(99)), LatencyClass::Fast);
+        assert_eq!(class(ms(100)), LatencyClass::Normal);
+        assert_eq!(class(ms(499)), LatencyClass::Normal);
+        assert_eq!(class(ms(500)), LatencyClass::Slow);
+        assert_eq!(class(ms(1999)), LatencyClass::Slow);
+        assert_eq!(class(2.seconds()), LatencyClass::Critical);
+        assert_eq!(class(Duration::MAX), LatencyClass::Critical);
+
+        // Equal bounds skip the classes in between.
+        let thresholds = LatencyThresholds::new(1.seconds(), 1.seconds(), 5.seconds());
+        assert_eq!(classify(ms(999), &thresholds), LatencyClass::Fast);
+        assert_eq!(classify(1.seconds(), &thresholds), LatencyClass::Slow);
+    }
+
+    #[test]
+    fn test_timeout_controller() {
+        let ms = Duration::from_millis;
+        let mut controller = TimeoutController::new(ms(50), 10.seconds());
+        assert_eq!(controller.current_timeout(), 10.seconds());
+
+        // 1% of requests take 1s, the rest between 100ms and 199ms.
+        for i in 0..1000u64 {
+            let latency = if i % 100 == 99 {
+                ms(1000)
+            } else {
+                ms(100 + i % 100)
+            };
+            controller.observe(latency);
+        }
+        let timeout = controller.current_timeout();
+        assert!(timeout >= ms(396) && timeout <= 2.seconds(), "{timeout:?}");
+
+        // The timeout follows the latencies once the window has rolled over.
+        for _ in 0..100 {
+            controller.observe(ms(10));
+        }
+        assert_eq!(controller.current_timeout(), ms(50));
+        for _ in 0..100 {
+            controller.observe(20.seconds());
+        }
+        assert_eq!(controller.current_timeout(), 10.seconds());
+
+        let mut median = TimeoutController::new(Duration::ZERO, 10.seconds())
+            .window(3)
+            .percentile(50.0)
+            .multiplier(1.5);
+        for latency in [ms(100), ms(900), ms(200), ms(400)] {
+            median.observe(latency);
+        }
+        assert_eq!(median.current_timeout(), ms(600));
+    }
+
+    #[test]
+    fn test_timeout_controller_shrink_window() {
+        let ms = Duration::from_millis;
+        let mut controller = TimeoutController::new(Duration::ZERO, 10.seconds())
+            .percentile(50.0)
+            .multiplier(1.0);
+        for latency in [ms(900), ms(800), ms(700), ms(100), ms(200), ms(300)] {
+            controller.observe(latency);
+        }
+        assert_eq!(controller.current_timeout(), ms(500));
+
+        // Shrinking keeps the most recent samples.
+        let mut controller = controller.window(3);
+        assert_eq!(controller.current_timeout(), ms(200));
+        controller.observe(ms(400));
+        assert_eq!(controller.current_timeout(), ms(300));
+    }
+
+    #[test]
+    #[should_panic(expected = "ascending order")]
+    fn test_thresholds_unordered() {
+        LatencyThresholds::new(1.seconds(), 500.seconds(), 2.seconds());
+    }
+}
@@ -15,7 +15,43 @@
 //! }
 //! ````
 
-use std::time::Duration;
+pub mod budget;
+pub mod clock;
+pub mod compare;
+pub mod consts;
+pub mod convert;
+pub mod deadline;
+pub mod env;
+mod ext;
+pub mod format;
+pub mod iter;
+pub mod keepalive;
+pub mod latency;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+pub mod parse;
+#[cfg(feature = "rand")]
+pub mod random;
+pub mod rate;
+pub mod retry;
+pub mod round;
+pub mod saturating;
+pub mod schedule;
+pub mod scope;
+#[cfg(feature = "serde")]
+pub mod serde;
+pub mod signed;
+pub mod split;
+pub mod stats;
+pub mod unit;
+pub mod validate;
+pub mod window;
+
+use std::time::{Duration, SystemTime};
+
+pub use ext::DurationExt;
+pub use parse::{parse, ParseError};
+pub use unit::Unit;
 
 /// Extension trait that simplifies construction [`std::time::Duration`] from
 /// numeric values.
@@ -42,29 +78,207 @@ pub trait EasyDuration: Sized {
     /// Creates a [`std::time::Duration`] by converting `self` to seconds.
     fn seconds(self) -> Duration;
 
+    /// Returns a zero [`std::time::Duration`].
+    ///
+    /// Unlike the other methods this takes no receiver, so generic code can
+    /// get a zero duration from `T::zero()` without a value of `T`. It is
+    /// equal to `0.seconds()` for every implementor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use easyduration::EasyDuration;
+    ///
+    /// fn total<T: EasyDuration>(values: Vec<T>) -> Duration {
+    ///     values.into_iter().fold(T::zero(), |sum, v| sum + v.seconds())
+    /// }
+    ///
+    /// assert_eq!(total(vec![1u8, 2, 3]), 6.seconds());
+    /// assert_eq!(total::<u8>(vec![]), Duration::ZERO);
+    /// ```
+    fn zero() -> Duration {
+        Duration::ZERO
+    }
+
     /// Creates a [`std::time::Duration`] by converting `self` to minutes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result overflows [`std::time::Duration`]. This applies
+    /// to all methods that scale the value to a larger unit.
     fn minutes(self) -> Duration {
-        self.seconds() * 60
+        scale(self.seconds(), 60)
     }
 
     /// Creates a [`std::time::Duration`] by converting `self` to hours.
     fn hours(self) -> Duration {
-        self.seconds() * 60 * 60
+        scale(self.seconds(), 60 * 60)
     }
 
     /// Creates a [`std::time::Duration`] by converting `self` to days.
     ///
     /// A day is defined as 24 hours.
     fn days(self) -> Duration {
-        self.seconds() * 60 * 60 * 24
+        scale(self.seconds(), 60 * 60 * 24)
     }
 
     /// Creates a [`std::time::Duration`] by converting `self` to years.
     ///
     /// A year is defined as 365 days.
     fn years(self) -> Duration {
-        self.days() * 365
+        scale(self.seconds(), 60 * 60 * 24 * 365)
+    }
+
+    /// Creates a [`std::time::Duration`] spanned by `self` samples at a
+    /// sample rate of `rate_hz`.
+    ///
+    /// The result is truncated to whole nanoseconds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rate_hz` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use easyduration::EasyDuration;
+    ///
+    /// assert_eq!(44100.samples(44100), 1.seconds());
+    /// ```
+    fn samples(self, rate_hz: u32) -> Duration {
+        assert!(rate_hz != 0, "sample rate must not be zero");
+        self.seconds() / rate_hz
     }
+
+    /// Creates a [`std::time::Duration`] by converting `self` to seconds and
+    /// rounding up to the next whole multiple of `quantum`.
+    ///
+    /// See [`round::ceil_to_tick`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use easyduration::EasyDuration;
+    ///
+    /// let quantum = Duration::from_millis(16);
+    /// assert_eq!(1.aligned_to(quantum), Duration::from_millis(1008));
+    /// ```
+    fn aligned_to(self, quantum: Duration) -> Duration {
+        round::ceil_to_tick(self.seconds(), quantum)
+    }
+
+    /// Creates a [`std::time::Duration`] by converting `self` to seconds,
+    /// returning an error if it is zero.
+    ///
+    /// Useful where a zero duration is a bug, like poll intervals or TTLs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use easyduration::EasyDuration;
+    ///
+    /// assert!(0.nonzero().is_err());
+    /// assert_eq!(1.nonzero(), Ok(1.seconds()));
+    /// ```
+    fn nonzero(self) -> Result<Duration, validate::ZeroDurationError> {
+        let d = self.seconds();
+        if d.is_zero() {
+            Err(validate::ZeroDurationError)
+        } else {
+            Ok(d)
+        }
+    }
+
+    /// Creates a [`std::time::Duration`] by converting `self` to seconds and
+    /// clamping it to `[min, max]` seconds.
+    ///
+    /// `min` must not exceed `max`, which is checked in debug builds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use easyduration::EasyDuration;
+    ///
+    /// assert_eq!(5.seconds_clamped(10, 60), 10.seconds());
+    /// assert_eq!(90.seconds_clamped(10, 60), 60.seconds());
+    /// ```
+    fn seconds_clamped(self, min: u64, max: u64) -> Duration {
+        debug_assert!(min <= max, "seconds_clamped: min must not exceed max");
+        self.seconds()
+            .max(Duration::from_secs(min))
+            .min(Duration::from_secs(max))
+    }
+
+    /// Returns the total time of `self` iterations costing `per_item` each.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result overflows [`std::time::Duration`]. See
+    /// [`EasyDuration::checked_total_for`] and
+    /// [`EasyDuration::saturating_total_for`] for non-panicking variants.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use easyduration::EasyDuration;
+    ///
+    /// assert_eq!(1000.total_for(Duration::from_millis(5)), 5.seconds());
+    /// ```
+    fn total_for(self, per_item: Duration) -> Duration {
+        self.checked_total_for(per_item)
+            .expect("overflow when multiplying duration")
+    }
+
+    /// Returns the total time of `self` iterations costing `per_item` each,
+    /// or `None` if the result overflows [`std::time::Duration`].
+    fn checked_total_for(self, per_item: Duration) -> Option<Duration> {
+        let count = self.seconds().as_secs();
+        per_item
+            .as_nanos()
+            .checked_mul(count.into())
+            .and_then(checked_from_nanos)
+    }
+
+    /// Returns the total time of `self` iterations costing `per_item` each,
+    /// saturating at [`Duration::MAX`].
+    fn saturating_total_for(self, per_item: Duration) -> Duration {
+        self.checked_total_for(per_item).unwrap_or(Duration::MAX)
+    }
+
+    /// Returns the point in time `self` seconds after `base`.
+    ///
+    /// For other units use [`DurationExt::after`] on the constructed
+    /// duration, like `7.days().after(base)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result can't be represented as a [`SystemTime`].
+    fn after(self, base: SystemTime) -> SystemTime {
+        self.seconds().after(base)
+    }
+
+    /// Returns the point in time `self` seconds before `base`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result can't be represented as a [`SystemTime`].
+    fn before(self, base: SystemTime) -> SystemTime {
+        self.seconds().before(base)
+    }
+}
+
+/// Multiplies `d` by `factor`.
+///
+/// The product is computed in `u128` nanoseconds, so only the final result
+/// needs to fit into a [`Duration`].
+fn scale(d: Duration, factor: u64) -> Duration {
+    d.as_nanos()
+        .checked_mul(factor.into())
+        .and_then(checked_from_nanos)
+        .expect("overflow when constructing duration")
 }
 
 impl EasyDuration for u8 {
@@ -125,6 +339,47 @@ impl EasyDuration for i64 {
     }
 }
 
+/// Implements [`EasyDuration`] for a newtype by delegating to an inner value.
+///
+/// The part after `=>` is a field or method access on `self` that yields a
+/// type implementing [`EasyDuration`].
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{impl_easy_duration, EasyDuration};
+///
+/// struct Timeout(u64);
+/// impl_easy_duration!(Timeout => .0);
+///
+/// struct Retries {
+///     count: u32,
+/// }
+/// impl_easy_duration!(Retries => .count);
+///
+/// assert_eq!(Timeout(2).minutes(), 120.seconds());
+/// assert_eq!(Retries { count: 3 }.seconds(), 3.seconds());
+/// ```
+#[macro_export]
+macro_rules! impl_easy_duration {
+    ($ty:ty => . $($access:tt)+) => {
+        impl $crate::EasyDuration for $ty {
+            fn seconds(self) -> ::std::time::Duration {
+                $crate::EasyDuration::seconds(self.$($access)+)
+            }
+        }
+    };
+}
+
+/// Converts a nanosecond count into a [`Duration`], returning `None` if it
+/// exceeds [`Duration::MAX`].
+pub(crate) fn checked_from_nanos(nanos: u128) -> Option<Duration> {
+    const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+    let secs = u64::try_from(nanos / NANOS_PER_SEC).ok()?;
+    Some(Duration::new(secs, (nanos % NANOS_PER_SEC) as u32))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -181,4 +436,144 @@ mod tests {
         assert_eq!(1u32.years(), Duration::from_secs(60 * 60 * 24 * 365));
         assert_eq!(1u64.years(), Duration::from_secs(60 * 60 * 24 * 365));
     }
+
+    #[test]
+    fn test_samples() {
+        assert_eq!(44100.samples(44100), Duration::from_secs(1));
+        assert_eq!(96000.samples(48000), Duration::from_secs(2));
+        assert_eq!(22050u32.samples(44100), Duration::from_millis(500));
+        assert_eq!(480.samples(48000), Duration::from_millis(10));
+        assert_eq!(1.samples(48000), Duration::from_nanos(20_833));
+        assert_eq!(0.samples(48000), Duration::ZERO);
+    }
+
+    #[test]
+    #[should_panic(expected = "sample rate must not be zero")]
+    fn test_samples_zero_rate() {
+        100.samples(0);
+    }
+
+    #[test]
+    fn test_aligned_to() {
+        let quantum = Duration::from_millis(16);
+        assert_eq!(0.aligned_to(quantum), Duration::ZERO);
+        assert_eq!(1.aligned_to(quantum), Duration::from_millis(1008));
+        assert_eq!(2u8.aligned_to(quantum), Duration::from_millis(2000));
+        assert_eq!((-3i32).aligned_to(quantum), Duration::from_millis(3008));
+        assert_eq!(5u64.aligned_to(Duration::ZERO), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_nonzero() {
+        assert_eq!(0.nonzero(), Err(validate::ZeroDurationError));
+        assert_eq!(0u64.nonzero(), Err(validate::ZeroDurationError));
+        assert_eq!(1.nonzero(), Ok(Duration::from_secs(1)));
+        assert_eq!((-7i16).nonzero(), Ok(Duration::from_secs(7)));
+    }
+
+    #[test]
+    fn test_after_before() {
+        let base = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+
+        assert_eq!(30.after(base), base + Duration::from_secs(30));
+        assert_eq!(30.before(base), base - Duration::from_secs(30));
+        assert_eq!(7.days().after(base), base + Duration::from_secs(7 * 86_400));
+        assert_eq!(
+            7.days().before(base),
+            base - Duration::from_secs(7 * 86_400)
+        );
+        assert_eq!((-5i8).after(base), 5.after(base));
+    }
+
+    #[test]
+    fn test_overflow_boundary() {
+        const SECS_PER_YEAR: u64 = 60 * 60 * 24 * 365;
+
+        let max_years = u64::MAX / SECS_PER_YEAR;
+        assert_eq!(
+            max_years.years(),
+            Duration::from_secs(max_years * SECS_PER_YEAR)
+        );
+        assert_eq!((max_years * 365).days(), max_years.years());
+        assert_eq!((u64::MAX / 60).minutes().as_secs(), u64::MAX / 60 * 60);
+        assert_eq!((u64::MAX / 3600).hours().as_secs(), u64::MAX / 3600 * 3600);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow when constructing duration")]
+    fn test_years_overflow() {
+        (u64::MAX / (60 * 60 * 24 * 365) + 1).years();
+    }
+
+    #[test]
+    fn test_zero() {
+        fn longest<T: EasyDuration + Copy>(values: &[T]) -> Duration {
+            values
+                .iter()
+                .map(|v| v.seconds())
+                .fold(T::zero(), Duration::max)
+        }
+
+        assert_eq!(longest(&[3u32, 7, 5]), 7.seconds());
+        assert_eq!(longest::<i64>(&[]), Duration::ZERO);
+        assert_eq!(u8::zero(), 0u8.seconds());
+        assert_eq!(i32::zero(), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_seconds_clamped() {
+        assert_eq!(5.seconds_clamped(10, 60), 10.seconds());
+        assert_eq!(30.seconds_clamped(10, 60), 30.seconds());
+        assert_eq!(90.seconds_clamped(10, 60), 60.seconds());
+        assert_eq!(10u8.seconds_clamped(10, 60), 10.seconds());
+        assert_eq!(60i64.seconds_clamped(10, 60), 60.seconds());
+        assert_eq!((-90).seconds_clamped(0, 60), 60.seconds());
+        assert_eq!(7.seconds_clamped(7, 7), 7.seconds());
+    }
+
+    #[test]
+    fn test_total_for() {
+        let ms = Duration::from_millis;
+
+        assert_eq!(1000.total_for(ms(5)), 5.seconds());
+        assert_eq!(0.total_for(ms(5)), Duration::ZERO);
+        assert_eq!(7u8.total_for(1.minutes()), 7.minutes());
+        assert_eq!((-3).total_for(ms(10)), ms(30));
+        assert_eq!(
+            3.total_for(Duration::from_nanos(333_333_333)),
+            Duration::from_nanos(999_999_999)
+        );
+
+        assert_eq!(2.checked_total_for(ms(1)), Some(ms(2)));
+        assert_eq!(2u64.checked_total_for(Duration::MAX), None);
+        assert_eq!(u64::MAX.checked_total_for(2.seconds()), None);
+        assert_eq!(1u64.checked_total_for(Duration::MAX), Some(Duration::MAX));
+
+        assert_eq!(u64::MAX.saturating_total_for(1.hours()), Duration::MAX);
+        assert_eq!(5.saturating_total_for(ms(2)), ms(10));
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow")]
+    fn test_total_for_overflow() {
+        u64::MAX.total_for(1.days());
+    }
+
+    #[test]
+    fn test_impl_easy_duration() {
+        struct Timeout(u64);
+        impl_easy_duration!(Timeout => .0);
+
+        struct Delay(i32);
+        impl Delay {
+            fn value(&self) -> i32 {
+                self.0
+            }
+        }
+        impl_easy_duration!(Delay => .value());
+
+        assert_eq!(Timeout(2).seconds(), Duration::from_secs(2));
+        assert_eq!(Timeout(2).minutes(), Duration::from_secs(120));
+        assert_eq!(Delay(-3).minutes(), Duration::from_secs(180));
+    }
 }
@@ -0,0 +1,53 @@
+//! Integration with the [`metrics`](https://docs.rs/metrics) crate.
+//!
+//! Requires the `metrics` feature.
+//!
+//! All durations are recorded as `f64` seconds, so histograms fed through this
+//! module share a common unit regardless of where the timings came from.
+
+use std::time::Duration;
+
+/// Records `d` to the histogram `name` as seconds.
+pub fn record_histogram(name: &'static str, d: Duration) {
+    ::metrics::histogram!(name).record(d.as_secs_f64());
+}
+
+/// Describes the histogram `name`, registering [`metrics::Unit::Seconds`] as
+/// its unit.
+///
+/// Call this once at startup for histograms recorded with
+/// [`record_histogram`] so exporters can render the unit.
+pub fn describe_histogram(name: &'static str, description: &'static str) {
+    ::metrics::describe_histogram!(name, ::metrics::Unit::Seconds, description);
+}
+
+#[cfg(test)]
+mod tests {
+    use metrics_util::debugging::{DebugValue, DebuggingRecorder};
+
+    use super::*;
+
+    #[test]
+    fn test_record_histogram() {
+        let recorder = DebuggingRecorder::new();
+        let snapshotter = recorder.snapshotter();
+
+        ::metrics::with_local_recorder(&recorder, || {
+            describe_histogram("request_duration", "Request duration");
+            record_histogram("request_duration", Duration::from_millis(1500));
+        });
+
+        let snapshot = snapshotter.snapshot().into_vec();
+        assert_eq!(snapshot.len(), 1);
+        let (key, unit, _, value) = &snapshot[0];
+        assert_eq!(key.key().name(), "request_duration");
+        assert_eq!(*unit, Some(::metrics::Unit::Seconds));
+        match value {
+            DebugValue::Histogram(values) => {
+                assert_eq!(values.len(), 1);
+                assert_eq!(values[0].into_inner(), 1.5);
+            }
+            other => panic!("expected histogram, got {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,790 @@
+//! Parsing of human readable duration strings like `"1h30m"`.
+
+use std::{collections::HashMap, fmt, time::Duration};
+
+use crate::{signed::SignedDuration, Unit};
+
+/// Error returned when a duration string cannot be parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseError {
+    /// The input was empty.
+    Empty,
+    /// A number was expected but not found.
+    InvalidNumber,
+    /// A number was not followed by a unit.
+    MissingUnit,
+    /// The unit is not recognized.
+    UnknownUnit(String),
+    /// The duration does not fit into a [`Duration`].
+    Overflow,
+    /// A line is not of the form `key=duration`.
+    InvalidEntry,
+    /// Parsing failed on the given line (starting at 1) of a multi-line input.
+    Line { line: usize, error: Box<ParseError> },
+    /// The input is not a valid ISO 8601 duration.
+    InvalidIso8601,
+    /// Parsing failed on the given element (starting at 0) of a list.
+    Element {
+        index: usize,
+        error: Box<ParseError>,
+    },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Empty => f.write_str("empty duration string"),
+            ParseError::InvalidNumber => f.write_str("expected a number"),
+            ParseError::MissingUnit => f.write_str("missing unit after number"),
+            ParseError::UnknownUnit(unit) => write!(f, "unknown unit '{unit}'"),
+            ParseError::Overflow => f.write_str("duration is too large"),
+            ParseError::InvalidEntry => f.write_str("expected an entry of the form key=duration"),
+            ParseError::Line { line, error } => write!(f, "line {line}: {error}"),
+            ParseError::InvalidIso8601 => f.write_str("invalid ISO 8601 duration"),
+            ParseError::Element { index, error } => write!(f, "element {index}: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses a duration string like `"30s"`, `"1h30m"` or `"1 hour, 30 minutes"`.
+///
+/// The input is a sequence of `<number><unit>` segments which are summed up.
+/// A unit may appear more than once, in which case all its segments are
+/// added. Whitespace is allowed between a number and its unit, and segments
+/// may be separated by whitespace, a comma and/or the word `and`. Leading and
+/// trailing whitespace is ignored. Recognized units are:
+///
+/// * `ns`, `nanos`, `nanosecond(s)`
+/// * `us`, `µs`, `micros`, `microsecond(s)`
+/// * `ms`, `millis`, `millisecond(s)`
+/// * `s`, `sec(s)`, `second(s)`
+/// * `m`, `min(s)`, `minute(s)`
+/// * `h`, `hr(s)`, `hour(s)`
+/// * `d`, `day(s)`
+/// * `w`, `week(s)`
+/// * `y`, `year(s)`
+///
+/// Numbers may use the `0x`, `0o` or `0b` prefix for hexadecimal, octal or
+/// binary integers, like `"0x10s"`. Prefixed numbers can't have a fractional
+/// part. Hexadecimal digits are read greedily, so a unit starting with one of
+/// `a`-`f` (like `d`) must be separated from a hexadecimal number by
+/// whitespace.
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{parse, EasyDuration};
+///
+/// assert_eq!(parse("1h30m").unwrap(), 90.minutes());
+/// assert_eq!(parse("1 hour and 30 minutes").unwrap(), 90.minutes());
+/// ```
+pub fn parse(s: &str) -> Result<Duration, ParseError> {
+    let mut rest = s.trim();
+    if rest.is_empty() {
+        return Err(ParseError::Empty);
+    }
+
+    let mut total = Duration::ZERO;
+    while !rest.is_empty() {
+        let (segment, tail) = parse_segment(rest)?;
+        total = total.checked_add(segment).ok_or(ParseError::Overflow)?;
+        rest = skip_separator(tail)?;
+    }
+    Ok(total)
+}
+
+/// Parses a duration string with an optional leading sign, like `"-1h30m"`.
+///
+/// The sign must directly precede the first number and applies to the whole
+/// duration. The rest of the input is parsed with [`parse()`].
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{parse::parse_signed, signed::SignedDuration, EasyDuration};
+///
+/// assert_eq!(parse_signed("-1h30m").unwrap(), SignedDuration::negative(90.minutes()));
+/// assert_eq!(parse_signed("+30s").unwrap(), SignedDuration::positive(30.seconds()));
+/// ```
+pub fn parse_signed(s: &str) -> Result<SignedDuration, ParseError> {
+    let s = s.trim();
+    let (negative, rest) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+    if rest.len() != s.len() && rest.starts_with(char::is_whitespace) {
+        return Err(ParseError::InvalidNumber);
+    }
+    Ok(SignedDuration::new(parse(rest)?, negative))
+}
+
+/// Parses a duration from the start of `s`, returning it together with the
+/// number of bytes consumed.
+///
+/// This accepts the same grammar as [`parse()`], but stops at the first input
+/// that isn't part of a duration and leaves it for the caller. Leading
+/// whitespace is consumed, whitespace and separators after the last segment
+/// are not. Fails if `s` doesn't start with a duration.
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{parse::parse_prefix, EasyDuration};
+///
+/// let input = "30s remaining";
+/// let (d, len) = parse_prefix(input).unwrap();
+/// assert_eq!(d, 30.seconds());
+/// assert_eq!(&input[len..], " remaining");
+/// ```
+pub fn parse_prefix(s: &str) -> Result<(Duration, usize), ParseError> {
+    let rest = s.trim_start();
+    if rest.is_empty() {
+        return Err(ParseError::Empty);
+    }
+
+    let (mut total, mut tail) = parse_segment(rest)?;
+    loop {
+        let next = match skip_separator(tail) {
+            Ok(next) if !next.is_empty() => next,
+            _ => break,
+        };
+        match parse_segment(next) {
+            Ok((segment, next_tail)) => {
+                total = total.checked_add(segment).ok_or(ParseError::Overflow)?;
+                tail = next_tail;
+            }
+            Err(ParseError::Overflow) => return Err(ParseError::Overflow),
+            Err(_) => break,
+        }
+    }
+    Ok((total, s.len() - tail.len()))
+}
+
+/// Parses a clock-style duration like `"03:04:05"` with an optional day
+/// prefix like `"2d 03:04:05"`, as printed by `uptime` and similar tools.
+///
+/// Hours may have any number of digits, minutes and seconds must be below 60.
+/// Leading and trailing whitespace is ignored.
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{parse::parse_clock, EasyDuration};
+///
+/// assert_eq!(parse_clock("03:04:05").unwrap(), 3.hours() + 4.minutes() + 5.seconds());
+/// assert_eq!(parse_clock("2d 03:04:05").unwrap(), 2.days() + 3.hours() + 4.minutes() + 5.seconds());
+/// ```
+pub fn parse_clock(s: &str) -> Result<Duration, ParseError> {
+    let s = s.trim();
+    if s.is_empty() {
+        return Err(ParseError::Empty);
+    }
+
+    let (days, clock) = match s.split_once(char::is_whitespace) {
+        Some((days, clock)) => {
+            let days = days.strip_suffix('d').ok_or(ParseError::InvalidNumber)?;
+            (clock_field(days)?, clock.trim_start())
+        }
+        None => (0, s),
+    };
+
+    let mut fields = clock.split(':');
+    let (Some(hours), Some(minutes), Some(seconds), None) =
+        (fields.next(), fields.next(), fields.next(), fields.next())
+    else {
+        return Err(ParseError::InvalidNumber);
+    };
+    let (hours, minutes, seconds) = (
+        clock_field(hours)?,
+        clock_field(minutes)?,
+        clock_field(seconds)?,
+    );
+    if minutes >= 60 || seconds >= 60 {
+        return Err(ParseError::InvalidNumber);
+    }
+
+    let secs = u128::from(days) * 86_400
+        + u128::from(hours) * 3600
+        + u128::from(minutes) * 60
+        + u128::from(seconds);
+    let secs = u64::try_from(secs).map_err(|_| ParseError::Overflow)?;
+    Ok(Duration::from_secs(secs))
+}
+
+/// Parses a non-empty run of decimal digits.
+fn clock_field(s: &str) -> Result<u64, ParseError> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(ParseError::InvalidNumber);
+    }
+    s.parse().map_err(|_| ParseError::Overflow)
+}
+
+/// Parses an ISO 8601 duration like `"PT1H30M"` or `"P1DT0.5S"`.
+///
+/// Supported designators are `W` and `D` before the `T` and `H`, `M` and `S`
+/// after it, each at most once and in this order. Weeks are 7 days and days
+/// are 24 hours. Years and months have no fixed length and are rejected.
+/// Seconds may have a fractional part separated by `.` or `,`, digits beyond
+/// nanosecond precision are truncated.
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{parse::parse_iso8601, EasyDuration};
+///
+/// assert_eq!(parse_iso8601("PT1H30M").unwrap(), 90.minutes());
+/// assert_eq!(parse_iso8601("P2D").unwrap(), 2.days());
+/// ```
+pub fn parse_iso8601(s: &str) -> Result<Duration, ParseError> {
+    let rest = s.strip_prefix('P').ok_or(ParseError::InvalidIso8601)?;
+    let (date, time) = match rest.split_once('T') {
+        Some((_, "")) => return Err(ParseError::InvalidIso8601),
+        Some((date, time)) => (date, time),
+        None => (rest, ""),
+    };
+    if date.is_empty() && time.is_empty() {
+        return Err(ParseError::InvalidIso8601);
+    }
+
+    let mut total = Duration::ZERO;
+    for (part, units) in [
+        (date, &[('W', Unit::Weeks), ('D', Unit::Days)][..]),
+        (
+            time,
+            &[
+                ('H', Unit::Hours),
+                ('M', Unit::Minutes),
+                ('S', Unit::Seconds),
+            ][..],
+        ),
+    ] {
+        let mut rest = part;
+        let mut units = units.iter();
+        while !rest.is_empty() {
+            let end = rest
+                .find(|c: char| !c.is_ascii_digit() && c != '.' && c != ',')
+                .ok_or(ParseError::InvalidIso8601)?;
+            let (number, tail) = rest.split_at(end);
+            let designator = tail.chars().next().unwrap();
+            let &(_, unit) = units
+                .find(|(d, _)| *d == designator)
+                .ok_or(ParseError::InvalidIso8601)?;
+            let segment = iso8601_component(number, unit)?;
+            total = total.checked_add(segment).ok_or(ParseError::Overflow)?;
+            rest = &tail[designator.len_utf8()..];
+        }
+    }
+    Ok(total)
+}
+
+/// Parses the number of an ISO 8601 component. Only seconds may have a
+/// fractional part.
+fn iso8601_component(number: &str, unit: Unit) -> Result<Duration, ParseError> {
+    let (whole, fraction) = match number.split_once(['.', ',']) {
+        Some((whole, fraction)) if unit == Unit::Seconds => (whole, Some(fraction)),
+        Some(_) => return Err(ParseError::InvalidIso8601),
+        None => (number, None),
+    };
+    if whole.is_empty() || fraction.is_some_and(|f| f.is_empty() || f.contains(['.', ','])) {
+        return Err(ParseError::InvalidIso8601);
+    }
+    let value = whole.parse().map_err(|_| ParseError::Overflow)?;
+    let mut d = unit.checked_mul(value).ok_or(ParseError::Overflow)?;
+    if let Some(fraction) = fraction {
+        let digits = &fraction[..fraction.len().min(9)];
+        let nanos: u64 = format!("{digits:0<9}").parse().unwrap();
+        d = d
+            .checked_add(Duration::from_nanos(nanos))
+            .ok_or(ParseError::Overflow)?;
+    }
+    Ok(d)
+}
+
+/// Parses a duration string, returning `default` if it is invalid.
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{parse::parse_or, EasyDuration};
+///
+/// assert_eq!(parse_or("5s", 30.seconds()), 5.seconds());
+/// assert_eq!(parse_or("five seconds", 30.seconds()), 30.seconds());
+/// ```
+pub fn parse_or(s: &str, default: Duration) -> Duration {
+    parse(s).unwrap_or(default)
+}
+
+/// Parses a duration string, computing a fallback from the error if it is
+/// invalid.
+pub fn parse_or_else<F>(s: &str, f: F) -> Duration
+where
+    F: FnOnce(ParseError) -> Duration,
+{
+    parse(s).unwrap_or_else(f)
+}
+
+/// Parses a duration string, interpreting a bare number without unit as a
+/// count of `default`.
+///
+/// Inputs with explicit units are parsed as with [`parse`].
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{parse::parse_with_default_unit, EasyDuration, Unit};
+///
+/// assert_eq!(parse_with_default_unit("30", Unit::Seconds).unwrap(), 30.seconds());
+/// assert_eq!(parse_with_default_unit("2m", Unit::Seconds).unwrap(), 2.minutes());
+/// ```
+pub fn parse_with_default_unit(s: &str, default: Unit) -> Result<Duration, ParseError> {
+    match parse_number(s.trim()) {
+        Ok((value, "")) => default.checked_mul(value).ok_or(ParseError::Overflow),
+        _ => parse(s),
+    }
+}
+
+/// Parses a single `<number><unit>` segment from the start of `s`, returning
+/// the segment and the remaining input.
+fn parse_segment(s: &str) -> Result<(Duration, &str), ParseError> {
+    let (value, rest) = parse_number(s)?;
+    let rest = rest.trim_start();
+    let unit_end = rest
+        .find(|c: char| !c.is_alphabetic())
+        .unwrap_or(rest.len());
+    if unit_end == 0 {
+        return Err(ParseError::MissingUnit);
+    }
+    let unit: Unit = rest[..unit_end].parse()?;
+
+    let segment = unit.checked_mul(value).ok_or(ParseError::Overflow)?;
+    Ok((segment, &rest[unit_end..]))
+}
+
+/// Skips the separator between two segments, returning the remaining input.
+///
+/// A separator is any whitespace, optionally containing a comma and/or the
+/// word `and`. A separator that isn't followed by another segment is an error.
+fn skip_separator(s: &str) -> Result<&str, ParseError> {
+    let mut rest = s.trim_start();
+    let mut explicit = false;
+    if let Some(tail) = rest.strip_prefix(',') {
+        rest = tail.trim_start();
+        explicit = true;
+    }
+    if let Some(tail) = rest.strip_prefix("and") {
+        if tail.starts_with(char::is_whitespace) {
+            rest = tail.trim_start();
+            explicit = true;
+        }
+    }
+
+    if explicit && rest.is_empty() {
+        return Err(ParseError::InvalidNumber);
+    }
+    Ok(rest)
+}
+
+/// Parses an unsigned integer from the start of `s`, returning the value and
+/// the remaining input.
+///
+/// Accepts the `0x`, `0o` and `0b` prefixes for hexadecimal, octal and binary
+/// values.
+fn parse_number(s: &str) -> Result<(u64, &str), ParseError> {
+    let (radix, digits) = match s.get(..2) {
+        Some("0x") => (16, &s[2..]),
+        Some("0o") => (8, &s[2..]),
+        Some("0b") => (2, &s[2..]),
+        _ => (10, s),
+    };
+    let end = digits
+        .find(|c: char| !c.is_digit(radix))
+        .unwrap_or(digits.len());
+    if end == 0 {
+        return Err(ParseError::InvalidNumber);
+    }
+    let value = u64::from_str_radix(&digits[..end], radix).map_err(|_| ParseError::Overflow)?;
+
+    let rest = &digits[end..];
+    if radix != 10 && rest.starts_with('.') {
+        // Prefixed values are integers only.
+        return Err(ParseError::InvalidNumber);
+    }
+    Ok((value, rest))
+}
+
+/// Parses a list of durations separated by `sep`, like `"1s,2s,4s"`.
+///
+/// Each element is parsed with [`parse()`], so whitespace around elements is
+/// ignored. A failing element is reported as [`ParseError::Element`] with its
+/// index.
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{parse::parse_list, EasyDuration};
+///
+/// let schedule = parse_list("1s, 2s, 4s", ',').unwrap();
+/// assert_eq!(schedule, [1.seconds(), 2.seconds(), 4.seconds()]);
+/// ```
+pub fn parse_list(s: &str, sep: char) -> Result<Vec<Duration>, ParseError> {
+    s.split(sep)
+        .enumerate()
+        .map(|(index, element)| {
+            parse(element).map_err(|error| ParseError::Element {
+                index,
+                error: Box::new(error),
+            })
+        })
+        .collect()
+}
+
+/// Parses newline-separated `key=duration` pairs.
+///
+/// Keys and values are trimmed and blank lines are skipped. Values are parsed
+/// with [`parse`]. If a key appears more than once, the last value wins.
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{parse::parse_map, EasyDuration};
+///
+/// let map = parse_map("connect=5s\nread=30s").unwrap();
+/// assert_eq!(map["connect"], 5.seconds());
+/// assert_eq!(map["read"], 30.seconds());
+/// ```
+pub fn parse_map(s: &str) -> Result<HashMap<String, Duration>, ParseError> {
+    let mut map = HashMap::new();
+    for (index, line) in s.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let entry = parse_entry(line).map_err(|error| ParseError::Line {
+            line: index + 1,
+            error: Box::new(error),
+        })?;
+        map.insert(entry.0.to_string(), entry.1);
+    }
+    Ok(map)
+}
+
+fn parse_entry(line: &str) -> Result<(&str, Duration), ParseError> {
+    let (key, value) = line.split_once('=').ok_or(ParseError::InvalidEntry)?;
+    let key = key.trim();
+    if key.is_empty() {
+        return Err(ParseError::InvalidEntry);
+    }
+    Ok((key, parse(value)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EasyDuration;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse("30s"), Ok(30.seconds()));
+        assert_eq!(parse(" 1h30m "), Ok(90.minutes()));
+        assert_eq!(
+            parse("1d2h3m4s"),
+            Ok(1.days() + 2.hours() + 3.minutes() + 4.seconds())
+        );
+        assert_eq!(parse("2weeks"), Ok(14.days()));
+        assert_eq!(parse("1y"), Ok(1.years()));
+        assert_eq!(parse("1500ms"), Ok(Duration::from_millis(1500)));
+        assert_eq!(parse("10us5ns"), Ok(Duration::from_nanos(10_005)));
+        assert_eq!(parse("3µs"), Ok(Duration::from_micros(3)));
+        assert_eq!(parse("5minutes"), Ok(5.minutes()));
+
+        assert_eq!(parse(""), Err(ParseError::Empty));
+        assert_eq!(parse("  "), Err(ParseError::Empty));
+        assert_eq!(parse("s"), Err(ParseError::InvalidNumber));
+        assert_eq!(parse("30"), Err(ParseError::MissingUnit));
+        assert_eq!(parse("1h30"), Err(ParseError::MissingUnit));
+        assert_eq!(parse("30x"), Err(ParseError::UnknownUnit("x".to_string())));
+        assert_eq!(parse("99999999999999999999s"), Err(ParseError::Overflow));
+        assert_eq!(parse(&format!("{}y", u64::MAX)), Err(ParseError::Overflow));
+    }
+
+    #[test]
+    fn test_parse_separators() {
+        assert_eq!(parse("1h 30m"), Ok(90.minutes()));
+        assert_eq!(parse("1 hour, 30 minutes"), Ok(90.minutes()));
+        assert_eq!(parse("1h,30m"), Ok(90.minutes()));
+        assert_eq!(parse("1 hour and 30 minutes"), Ok(90.minutes()));
+        assert_eq!(parse("1 hour, and 30 minutes"), Ok(90.minutes()));
+        assert_eq!(parse("  1h \t 30 m  "), Ok(90.minutes()));
+        assert_eq!(parse("1h, 20m and 10m"), Ok(90.minutes()));
+        assert_eq!(parse("0x1 d"), Ok(1.days()));
+
+        assert_eq!(parse("1h,"), Err(ParseError::InvalidNumber));
+        assert_eq!(parse("1h and"), Err(ParseError::InvalidNumber));
+        assert_eq!(parse(",1h"), Err(ParseError::InvalidNumber));
+        assert_eq!(parse("1h,,30m"), Err(ParseError::InvalidNumber));
+        assert_eq!(parse("1h and and 30m"), Err(ParseError::InvalidNumber));
+        assert_eq!(
+            parse("1hand30m"),
+            Err(ParseError::UnknownUnit("hand".to_string()))
+        );
+        assert_eq!(parse("1 h 30"), Err(ParseError::MissingUnit));
+    }
+
+    #[test]
+    fn test_parse_prefixed_numbers() {
+        assert_eq!(parse("0x10s"), Ok(16.seconds()));
+        assert_eq!(parse("0xffms"), Ok(Duration::from_millis(255)));
+        assert_eq!(parse("0o17m"), Ok(15.minutes()));
+        assert_eq!(parse("0b101h"), Ok(5.hours()));
+        assert_eq!(parse("0x1h0x1em"), Ok(90.minutes()));
+        assert_eq!(parse("010s"), Ok(10.seconds()));
+
+        assert_eq!(parse("0x1.5s"), Err(ParseError::InvalidNumber));
+        assert_eq!(parse("0xs"), Err(ParseError::InvalidNumber));
+        assert_eq!(parse("0b2s"), Err(ParseError::InvalidNumber));
+        assert_eq!(parse("0x1d"), Err(ParseError::MissingUnit));
+        assert_eq!(parse("0x10000000000000000s"), Err(ParseError::Overflow));
+    }
+
+    #[test]
+    fn test_parse_signed() {
+        assert_eq!(
+            parse_signed("-1h30m"),
+            Ok(SignedDuration::negative(90.minutes()))
+        );
+        assert_eq!(
+            parse_signed("1h30m"),
+            Ok(SignedDuration::positive(90.minutes()))
+        );
+        assert_eq!(
+            parse_signed("+1h30m"),
+            Ok(SignedDuration::positive(90.minutes()))
+        );
+        assert_eq!(
+            parse_signed(" -5 minutes "),
+            Ok(SignedDuration::negative(5.minutes()))
+        );
+        assert_eq!(parse_signed("-0s"), Ok(SignedDuration::ZERO));
+        assert!(!parse_signed("-0s").unwrap().is_negative());
+
+        assert_eq!(parse_signed(""), Err(ParseError::Empty));
+        assert_eq!(parse_signed("-"), Err(ParseError::Empty));
+        assert_eq!(parse_signed("- 5s"), Err(ParseError::InvalidNumber));
+        assert_eq!(parse_signed("--5s"), Err(ParseError::InvalidNumber));
+        assert_eq!(parse_signed("+-5s"), Err(ParseError::InvalidNumber));
+        assert_eq!(parse_signed("1h -30m"), Err(ParseError::InvalidNumber));
+    }
+
+    #[test]
+    fn test_parse_prefix() {
+        assert_eq!(parse_prefix("30s remaining"), Ok((30.seconds(), 3)));
+        assert_eq!(parse_prefix("1h30m"), Ok((90.minutes(), 5)));
+        assert_eq!(parse_prefix("  1h 30m left"), Ok((90.minutes(), 8)));
+        assert_eq!(parse_prefix("1h and 30m, then"), Ok((90.minutes(), 10)));
+        assert_eq!(parse_prefix("5m and more"), Ok((5.minutes(), 2)));
+        assert_eq!(parse_prefix("5m,"), Ok((5.minutes(), 2)));
+        assert_eq!(parse_prefix("10s;rest"), Ok((10.seconds(), 3)));
+
+        assert_eq!(parse_prefix(""), Err(ParseError::Empty));
+        assert_eq!(parse_prefix("soon"), Err(ParseError::InvalidNumber));
+        assert_eq!(parse_prefix("30 "), Err(ParseError::MissingUnit));
+        assert_eq!(
+            parse_prefix("30 remaining"),
+            Err(ParseError::UnknownUnit("remaining".to_string()))
+        );
+        assert_eq!(
+            parse_prefix("1s 99999999999999999999s"),
+            Err(ParseError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_parse_clock() {
+        let hms = 3.hours() + 4.minutes() + 5.seconds();
+        assert_eq!(parse_clock("2d 03:04:05"), Ok(2.days() + hms));
+        assert_eq!(parse_clock("03:04:05"), Ok(hms));
+        assert_eq!(parse_clock("  3:4:5 "), Ok(hms));
+        assert_eq!(parse_clock("0d 00:00:00"), Ok(Duration::ZERO));
+        assert_eq!(parse_clock("123:59:59"), Ok(124.hours() - 1.seconds()));
+        assert_eq!(parse_clock("1d  23:00:00"), Ok(47.hours()));
+
+        assert_eq!(parse_clock(""), Err(ParseError::Empty));
+        assert_eq!(parse_clock("03:04"), Err(ParseError::InvalidNumber));
+        assert_eq!(parse_clock("03:04:05:06"), Err(ParseError::InvalidNumber));
+        assert_eq!(parse_clock("03:60:00"), Err(ParseError::InvalidNumber));
+        assert_eq!(parse_clock("03:00:60"), Err(ParseError::InvalidNumber));
+        assert_eq!(parse_clock("2 03:04:05"), Err(ParseError::InvalidNumber));
+        assert_eq!(parse_clock("2d"), Err(ParseError::InvalidNumber));
+        assert_eq!(parse_clock("+3:04:05"), Err(ParseError::InvalidNumber));
+        assert_eq!(parse_clock("03::05"), Err(ParseError::InvalidNumber));
+        assert_eq!(
+            parse_clock(&format!("{}:00:00", u64::MAX)),
+            Err(ParseError::Overflow)
+        );
+        assert_eq!(
+            parse_clock("99999999999999999999d 00:00:00"),
+            Err(ParseError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_parse_iso8601() {
+        assert_eq!(parse_iso8601("PT1H30M"), Ok(90.minutes()));
+        assert_eq!(parse_iso8601("PT0S"), Ok(Duration::ZERO));
+        assert_eq!(parse_iso8601("PT36H"), Ok(36.hours()));
+        assert_eq!(parse_iso8601("P2D"), Ok(2.days()));
+        assert_eq!(parse_iso8601("P1W"), Ok(7.days()));
+        assert_eq!(
+            parse_iso8601("P1DT2H3M4S"),
+            Ok(1.days() + 2.hours() + 3.minutes() + 4.seconds())
+        );
+        assert_eq!(parse_iso8601("PT1.5S"), Ok(Duration::from_millis(1500)));
+        assert_eq!(parse_iso8601("PT0,25S"), Ok(Duration::from_millis(250)));
+        assert_eq!(
+            parse_iso8601("PT0.0000000019S"),
+            Ok(Duration::from_nanos(1))
+        );
+
+        for invalid in [
+            "", "P", "PT", "1H", "pt1h", "PT1H30", "P1Y", "P1M", "PT1M1H", "PT1H1H", "P1H", "PT1D",
+            "PT1.5M", "PT.5S", "PT1.S", "PT1.2.3S", "P1DT", "PT-1S", "PT1H 30M",
+        ] {
+            assert_eq!(
+                parse_iso8601(invalid),
+                Err(ParseError::InvalidIso8601),
+                "{invalid}"
+            );
+        }
+        assert_eq!(
+            parse_iso8601("PT99999999999999999999S"),
+            Err(ParseError::Overflow)
+        );
+        assert_eq!(
+            parse_iso8601(&format!("PT{}H", u64::MAX)),
+            Err(ParseError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_parse_or() {
+        assert_eq!(parse_or("5s", 30.seconds()), 5.seconds());
+        assert_eq!(parse_or("5x", 30.seconds()), 30.seconds());
+        assert_eq!(parse_or("", 30.seconds()), 30.seconds());
+
+        assert_eq!(parse_or_else("5s", |_| unreachable!()), 5.seconds());
+        assert_eq!(
+            parse_or_else("5", |error| {
+                assert_eq!(error, ParseError::MissingUnit);
+                30.seconds()
+            }),
+            30.seconds()
+        );
+    }
+
+    #[test]
+    fn test_parse_with_default_unit() {
+        assert_eq!(
+            parse_with_default_unit("30", Unit::Seconds),
+            Ok(30.seconds())
+        );
+        assert_eq!(
+            parse_with_default_unit(" 30 ", Unit::Milliseconds),
+            Ok(Duration::from_millis(30))
+        );
+        assert_eq!(
+            parse_with_default_unit("0x10", Unit::Minutes),
+            Ok(16.minutes())
+        );
+        assert_eq!(
+            parse_with_default_unit("30ms", Unit::Seconds),
+            Ok(Duration::from_millis(30))
+        );
+        assert_eq!(
+            parse_with_default_unit("1h30m", Unit::Seconds),
+            Ok(90.minutes())
+        );
+
+        assert_eq!(
+            parse_with_default_unit("1h30", Unit::Seconds),
+            Err(ParseError::MissingUnit)
+        );
+        assert_eq!(
+            parse_with_default_unit("", Unit::Seconds),
+            Err(ParseError::Empty)
+        );
+        assert_eq!(
+            parse_with_default_unit(&u64::MAX.to_string(), Unit::Years),
+            Err(ParseError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_parse_map() {
+        let map = parse_map("connect=5s\n\n read = 30s \nwrite=10s\n").unwrap();
+        assert_eq!(map.len(), 3);
+        assert_eq!(map["connect"], 5.seconds());
+        assert_eq!(map["read"], 30.seconds());
+        assert_eq!(map["write"], 10.seconds());
+
+        // Duplicate keys: the last value wins.
+        let map = parse_map("read=1s\nread=2s").unwrap();
+        assert_eq!(map.len(), 1);
+        assert_eq!(map["read"], 2.seconds());
+
+        assert_eq!(
+            parse_map("connect=5s\nread=30x"),
+            Err(ParseError::Line {
+                line: 2,
+                error: Box::new(ParseError::UnknownUnit("x".to_string())),
+            })
+        );
+        assert_eq!(
+            parse_map("connect"),
+            Err(ParseError::Line {
+                line: 1,
+                error: Box::new(ParseError::InvalidEntry),
+            })
+        );
+        assert_eq!(
+            parse_map("=5s"),
+            Err(ParseError::Line {
+                line: 1,
+                error: Box::new(ParseError::InvalidEntry),
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_list() {
+        assert_eq!(
+            parse_list("1s,2s,4s,8s", ','),
+            Ok(vec![1.seconds(), 2.seconds(), 4.seconds(), 8.seconds()])
+        );
+        assert_eq!(
+            parse_list(" 1m30s ; 2h ", ';'),
+            Ok(vec![90.seconds(), 2.hours()])
+        );
+        assert_eq!(parse_list("5s", ','), Ok(vec![5.seconds()]));
+
+        let err = parse_list("1s,2s,x,8s", ',').unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::Element {
+                index: 2,
+                error: Box::new(ParseError::InvalidNumber)
+            }
+        );
+        assert_eq!(err.to_string(), "element 2: expected a number");
+
+        assert_eq!(
+            parse_list("1s,2s,", ','),
+            Err(ParseError::Element {
+                index: 2,
+                error: Box::new(ParseError::Empty)
+            })
+        );
+    }
+}
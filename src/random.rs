@@ -0,0 +1,85 @@
+//! Randomized durations.
+//!
+//! Requires the `rand` feature.
+
+use std::time::Duration;
+
+use rand::Rng;
+
+/// Returns a random duration between `min` and `max` (inclusive).
+///
+/// Values are sampled uniformly at nanosecond resolution using the thread
+/// local random number generator.
+pub fn random_between(min: Duration, max: Duration) -> Duration {
+    debug_assert!(min <= max, "random_between: min must not exceed max");
+    let nanos = rand::rng().random_range(min.as_nanos()..=max.as_nanos());
+    crate::checked_from_nanos(nanos).unwrap()
+}
+
+/// Returns an infinite iterator of jittered intervals around `base`.
+///
+/// Each interval is `base` perturbed independently by up to `±fraction` of
+/// its length, which spreads out retries and polls of many clients sharing
+/// the same schedule. The sign of `fraction` is ignored. Intervals never drop
+/// below zero and saturate at [`Duration::MAX`].
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{random::jittered_schedule, EasyDuration};
+///
+/// for interval in jittered_schedule(10.seconds(), 0.1).take(3) {
+///     assert!(interval >= 9.seconds() && interval <= 11.seconds());
+/// }
+/// ```
+pub fn jittered_schedule(base: Duration, fraction: f64) -> impl Iterator<Item = Duration> {
+    std::iter::repeat_with(move || {
+        let unit: f64 = rand::rng().random_range(-1.0..=1.0);
+        crate::ext::jitter(base, fraction, unit)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EasyDuration;
+
+    #[test]
+    fn test_random_between() {
+        let (min, max) = (1.seconds(), 2.seconds());
+        let samples: Vec<_> = (0..10_000).map(|_| random_between(min, max)).collect();
+
+        assert!(samples.iter().all(|d| (min..=max).contains(d)));
+        // The samples should cover the whole range.
+        assert!(samples.iter().any(|d| *d < Duration::from_millis(1100)));
+        assert!(samples.iter().any(|d| *d > Duration::from_millis(1900)));
+
+        assert_eq!(random_between(min, min), min);
+    }
+
+    #[test]
+    fn test_jittered_schedule() {
+        let base = 10.seconds();
+        let samples: Vec<_> = jittered_schedule(base, 0.2).take(10_000).collect();
+
+        assert!(samples
+            .iter()
+            .all(|d| *d >= 8.seconds() && *d <= 12.seconds()));
+        assert!(samples.windows(2).any(|w| w[0] != w[1]));
+
+        let mean = crate::stats::mean(&samples).unwrap();
+        let tolerance = Duration::from_millis(100);
+        assert!(
+            mean > base - tolerance && mean < base + tolerance,
+            "{mean:?}"
+        );
+
+        assert!(jittered_schedule(base, 0.0).take(10).all(|d| d == base));
+        assert!(jittered_schedule(base, -0.2)
+            .take(100)
+            .all(|d| d >= 8.seconds() && d <= 12.seconds()));
+        assert!(jittered_schedule(1.seconds(), 2.0)
+            .take(100)
+            .all(|d| d <= 3.seconds()));
+    }
+}
@@ -0,0 +1,250 @@
+//! Durations derived from rates and interpolation, like travel times and
+//! pacing delays.
+
+use std::time::Duration;
+
+/// Returns the time it takes to cover `distance` at `speed`.
+///
+/// `distance` and `speed` must use the same length unit and `speed` must be
+/// per second. Returns `None` if `speed` is zero, if either input is NaN or
+/// infinite, or if the result is negative or too large for a [`Duration`].
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{rate::travel_time, EasyDuration};
+///
+/// assert_eq!(travel_time(100.0, 10.0), Some(10.seconds()));
+/// assert_eq!(travel_time(100.0, 0.0), None);
+/// ```
+pub fn travel_time(distance: f64, speed: f64) -> Option<Duration> {
+    if speed == 0.0 || !distance.is_finite() || !speed.is_finite() {
+        return None;
+    }
+    Duration::try_from_secs_f64(distance / speed).ok()
+}
+
+/// Returns how long it takes for `count` events to happen at `rate_per_sec`
+/// events per second.
+///
+/// Returns `None` if the rate is zero, negative or NaN, or if the result is
+/// too large for a [`Duration`]. An infinite rate takes no time.
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{rate::duration_for_count, EasyDuration};
+///
+/// assert_eq!(duration_for_count(1000, 100.0), Some(10.seconds()));
+/// ```
+pub fn duration_for_count(count: u64, rate_per_sec: f64) -> Option<Duration> {
+    if rate_per_sec.is_nan() || rate_per_sec <= 0.0 {
+        return None;
+    }
+    Duration::try_from_secs_f64(count as f64 / rate_per_sec).ok()
+}
+
+/// Returns how long exponential decay with the given `half_life` takes to
+/// reduce a value to `remaining_fraction` of its start.
+///
+/// This is `half_life * log2(1 / remaining_fraction)`, so a fraction of `0.5`
+/// takes one half-life and `0.25` takes two. Returns `None` if the fraction
+/// is not in `(0, 1]` or the result is too large for a [`Duration`].
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{rate::decay_time, EasyDuration};
+///
+/// assert_eq!(decay_time(10.seconds(), 0.25), Some(20.seconds()));
+/// ```
+pub fn decay_time(half_life: Duration, remaining_fraction: f64) -> Option<Duration> {
+    if !(remaining_fraction > 0.0 && remaining_fraction <= 1.0) {
+        return None;
+    }
+    let half_lives = -remaining_fraction.log2();
+    Duration::try_from_secs_f64(half_life.as_secs_f64() * half_lives).ok()
+}
+
+/// Estimates the time left to finish `total` units of work, given that
+/// `done` units took `elapsed`.
+///
+/// Assumes a constant rate and saturates at [`Duration::MAX`]. Returns `None`
+/// if nothing is done yet, since no rate can be derived, and zero once `done`
+/// reaches `total`.
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{rate::eta, EasyDuration};
+///
+/// assert_eq!(eta(50, 100, 5.seconds()), Some(5.seconds()));
+/// ```
+pub fn eta(done: u64, total: u64, elapsed: Duration) -> Option<Duration> {
+    if done == 0 {
+        return None;
+    }
+    let left = u128::from(total.saturating_sub(done));
+    let nanos = elapsed
+        .as_nanos()
+        .checked_mul(left)
+        .map(|n| n / u128::from(done))
+        .and_then(crate::checked_from_nanos);
+    Some(nanos.unwrap_or(Duration::MAX))
+}
+
+/// Returns how long to wait after work that took `elapsed` to keep a steady
+/// `target_interval` between iterations.
+///
+/// Returns zero if the work already exceeded the interval.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use easyduration::rate::pace;
+///
+/// let frame = Duration::from_millis(16);
+/// assert_eq!(pace(Duration::from_millis(10), frame), Duration::from_millis(6));
+/// assert_eq!(pace(Duration::from_millis(20), frame), Duration::ZERO);
+/// ```
+pub fn pace(elapsed: Duration, target_interval: Duration) -> Duration {
+    target_interval.saturating_sub(elapsed)
+}
+
+/// Linearly interpolates between `a` and `b`.
+///
+/// `t` is clamped to `[0, 1]` and a NaN `t` is treated as zero, so the result
+/// is always between `a` and `b`. It is rounded to the nearest nanosecond.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use easyduration::{rate::lerp, EasyDuration};
+///
+/// assert_eq!(lerp(Duration::ZERO, 10.seconds(), 0.5), 5.seconds());
+/// ```
+pub fn lerp(a: Duration, b: Duration, t: f64) -> Duration {
+    let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+    let (a_nanos, b_nanos) = (a.as_nanos() as i128, b.as_nanos() as i128);
+    let offset = ((b_nanos - a_nanos) as f64 * t).round() as i128;
+    let nanos = (a_nanos + offset).clamp(a_nanos.min(b_nanos), a_nanos.max(b_nanos));
+    crate::checked_from_nanos(nanos as u128).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EasyDuration;
+
+    #[test]
+    fn test_travel_time() {
+        assert_eq!(travel_time(100.0, 10.0), Some(10.seconds()));
+        assert_eq!(travel_time(1.0, 4.0), Some(Duration::from_millis(250)));
+        assert_eq!(travel_time(0.0, 3.0), Some(Duration::ZERO));
+        assert_eq!(travel_time(-10.0, -2.0), Some(5.seconds()));
+
+        assert_eq!(travel_time(100.0, 0.0), None);
+        assert_eq!(travel_time(0.0, 0.0), None);
+        assert_eq!(travel_time(-100.0, 10.0), None);
+        assert_eq!(travel_time(f64::INFINITY, 10.0), None);
+        assert_eq!(travel_time(100.0, f64::INFINITY), None);
+        assert_eq!(travel_time(f64::NAN, 10.0), None);
+        assert_eq!(travel_time(f64::MAX, 1e-300), None);
+    }
+
+    #[test]
+    fn test_duration_for_count() {
+        assert_eq!(duration_for_count(1000, 100.0), Some(10.seconds()));
+        assert_eq!(
+            duration_for_count(3, 2.0),
+            Some(Duration::from_millis(1500))
+        );
+        assert_eq!(
+            duration_for_count(1, 3.0),
+            Some(Duration::from_nanos(333_333_333))
+        );
+        assert_eq!(duration_for_count(1, 0.5), Some(2.seconds()));
+        assert_eq!(duration_for_count(0, 100.0), Some(Duration::ZERO));
+        assert_eq!(
+            duration_for_count(1000, f64::INFINITY),
+            Some(Duration::ZERO)
+        );
+
+        assert_eq!(duration_for_count(1000, 0.0), None);
+        assert_eq!(duration_for_count(1000, -1.0), None);
+        assert_eq!(duration_for_count(1000, f64::NAN), None);
+        assert_eq!(duration_for_count(u64::MAX, 1e-300), None);
+    }
+
+    #[test]
+    fn test_decay_time() {
+        let half_life = 10.seconds();
+
+        assert_eq!(decay_time(half_life, 0.5), Some(half_life));
+        assert_eq!(decay_time(half_life, 0.25), Some(20.seconds()));
+        assert_eq!(decay_time(half_life, 0.125), Some(30.seconds()));
+        assert_eq!(decay_time(half_life, 1.0), Some(Duration::ZERO));
+        let d = decay_time(half_life, 0.1).unwrap();
+        assert!(d > 33.seconds() && d < 34.seconds(), "{d:?}");
+        assert_eq!(decay_time(Duration::ZERO, 0.5), Some(Duration::ZERO));
+
+        assert_eq!(decay_time(half_life, 0.0), None);
+        assert_eq!(decay_time(half_life, -0.5), None);
+        assert_eq!(decay_time(half_life, 1.5), None);
+        assert_eq!(decay_time(half_life, f64::NAN), None);
+        assert_eq!(decay_time(Duration::MAX, 0.25), None);
+    }
+
+    #[test]
+    fn test_eta() {
+        assert_eq!(eta(50, 100, 5.seconds()), Some(5.seconds()));
+        assert_eq!(eta(25, 100, 5.seconds()), Some(15.seconds()));
+        assert_eq!(
+            eta(3, 4, 1.seconds()),
+            Some(Duration::from_nanos(333_333_333))
+        );
+        assert_eq!(eta(100, 100, 5.seconds()), Some(Duration::ZERO));
+        assert_eq!(eta(150, 100, 5.seconds()), Some(Duration::ZERO));
+        assert_eq!(eta(1, 100, Duration::ZERO), Some(Duration::ZERO));
+        assert_eq!(eta(0, 100, 5.seconds()), None);
+
+        assert_eq!(eta(1, u64::MAX, 1.years()), Some(Duration::MAX));
+        assert_eq!(eta(1, u64::MAX, Duration::MAX), Some(Duration::MAX));
+    }
+
+    #[test]
+    fn test_pace() {
+        let ms = Duration::from_millis;
+
+        assert_eq!(pace(ms(10), ms(16)), ms(6));
+        assert_eq!(pace(Duration::ZERO, ms(16)), ms(16));
+        assert_eq!(pace(ms(16), ms(16)), Duration::ZERO);
+        assert_eq!(pace(ms(20), ms(16)), Duration::ZERO);
+        assert_eq!(pace(Duration::MAX, ms(16)), Duration::ZERO);
+        assert_eq!(pace(ms(5), Duration::ZERO), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_lerp() {
+        let ms = Duration::from_millis;
+
+        assert_eq!(lerp(Duration::ZERO, 10.seconds(), 0.0), Duration::ZERO);
+        assert_eq!(lerp(Duration::ZERO, 10.seconds(), 0.5), 5.seconds());
+        assert_eq!(lerp(Duration::ZERO, 10.seconds(), 1.0), 10.seconds());
+        assert_eq!(lerp(1.seconds(), 2.seconds(), 0.25), ms(1250));
+        // Decreasing intervals.
+        assert_eq!(lerp(10.seconds(), 2.seconds(), 0.5), 6.seconds());
+        assert_eq!(lerp(3.seconds(), 3.seconds(), 0.7), 3.seconds());
+
+        // Out of range t is clamped.
+        assert_eq!(lerp(1.seconds(), 2.seconds(), -1.0), 1.seconds());
+        assert_eq!(lerp(1.seconds(), 2.seconds(), 1.5), 2.seconds());
+        assert_eq!(lerp(1.seconds(), 2.seconds(), f64::INFINITY), 2.seconds());
+        assert_eq!(lerp(1.seconds(), 2.seconds(), f64::NAN), 1.seconds());
+
+        assert_eq!(lerp(Duration::ZERO, Duration::MAX, 1.0), Duration::MAX);
+        assert_eq!(lerp(Duration::MAX, Duration::ZERO, 1.0), Duration::ZERO);
+    }
+}
@@ -0,0 +1,265 @@
+//! Retry policies producing sequences of backoff delays.
+
+use std::{collections::hash_map::RandomState, hash::BuildHasher, time::Duration};
+
+use crate::DurationExt;
+
+/// A retry policy with exponential backoff, jitter, a delay cap and a limit on
+/// the number of retries.
+///
+/// By default delays double on each retry, are not capped or jittered and
+/// retries are unlimited. Jitter is seeded randomly unless a fixed
+/// [`seed`](RetryPolicy::seed) is set.
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{retry::RetryPolicy, EasyDuration};
+///
+/// let policy = RetryPolicy::new(1.seconds())
+///     .factor(2.0)
+///     .max_delay(10.seconds())
+///     .max_retries(5);
+/// let delays: Vec<_> = policy.delays().collect();
+/// assert_eq!(
+///     delays,
+///     [1.seconds(), 2.seconds(), 4.seconds(), 8.seconds(), 10.seconds()]
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct RetryPolicy {
+    base: Duration,
+    factor: f64,
+    max_delay: Duration,
+    jitter: f64,
+    max_retries: Option<usize>,
+    seed: Option<u64>,
+}
+
+impl RetryPolicy {
+    /// Creates a policy whose first delay is `base`.
+    pub fn new(base: Duration) -> Self {
+        Self {
+            base,
+            factor: 2.0,
+            max_delay: Duration::MAX,
+            jitter: 0.0,
+            max_retries: None,
+            seed: None,
+        }
+    }
+
+    /// Sets the factor each delay is multiplied by to get the next one.
+    pub fn factor(mut self, factor: f64) -> Self {
+        self.factor = factor;
+        self
+    }
+
+    /// Sets the largest delay, applied after jitter.
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Perturbs each delay randomly by up to `±fraction` of its length.
+    pub fn jitter(mut self, fraction: f64) -> Self {
+        self.jitter = fraction;
+        self
+    }
+
+    /// Limits the number of delays, and thus retries.
+    pub fn max_retries(mut self, max_retries: usize) -> Self {
+        self.max_retries = Some(max_retries);
+        self
+    }
+
+    /// Seeds the jitter, so the same policy always yields the same delays.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Returns an iterator over the delays before each retry.
+    pub fn delays(&self) -> impl Iterator<Item = Duration> {
+        let Self {
+            base,
+            factor,
+            max_delay,
+            jitter,
+            max_retries,
+            seed,
+        } = *self;
+        let seed = seed.unwrap_or_else(|| RandomState::new().hash_one(0u8));
+
+        let mut next = base.min(max_delay);
+        (0..max_retries.unwrap_or(usize::MAX)).map(move |attempt| {
+            let delay = next;
+            next = next.grow(factor, max_delay);
+            if jitter == 0.0 {
+                delay
+            } else {
+                delay
+                    .jitter_seeded(jitter, seed.wrapping_add(attempt as u64))
+                    .min(max_delay)
+            }
+        })
+    }
+}
+
+/// Sorts `delays` in ascending order and removes duplicates.
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{retry::normalize_schedule, EasyDuration};
+///
+/// let mut delays = vec![4.seconds(), 1.seconds(), 2.seconds(), 1.seconds()];
+/// normalize_schedule(&mut delays);
+/// assert_eq!(delays, [1.seconds(), 2.seconds(), 4.seconds()]);
+/// ```
+pub fn normalize_schedule(delays: &mut Vec<Duration>) {
+    delays.sort_unstable();
+    delays.dedup();
+}
+
+/// Caps every entry of `delays` at `max`.
+///
+/// Capping can introduce duplicates, use [`normalize_schedule`] afterwards if
+/// they are unwanted.
+pub fn cap_schedule(delays: &mut [Duration], max: Duration) {
+    for delay in delays.iter_mut() {
+        *delay = (*delay).min(max);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EasyDuration;
+
+    #[test]
+    fn test_attempts_and_growth() {
+        let policy = RetryPolicy::new(1.seconds()).max_retries(5);
+        let delays: Vec<_> = policy.delays().collect();
+        assert_eq!(
+            delays,
+            [
+                1.seconds(),
+                2.seconds(),
+                4.seconds(),
+                8.seconds(),
+                16.seconds()
+            ]
+        );
+
+        let policy = RetryPolicy::new(Duration::from_millis(100)).factor(1.5);
+        let delays: Vec<_> = policy.delays().take(3).collect();
+        assert_eq!(
+            delays,
+            [
+                Duration::from_millis(100),
+                Duration::from_millis(150),
+                Duration::from_millis(225)
+            ]
+        );
+
+        assert_eq!(
+            RetryPolicy::new(1.seconds())
+                .max_retries(0)
+                .delays()
+                .count(),
+            0
+        );
+        assert_eq!(
+            RetryPolicy::new(1.seconds()).delays().take(1000).count(),
+            1000
+        );
+    }
+
+    #[test]
+    fn test_capping() {
+        let policy = RetryPolicy::new(1.seconds())
+            .max_delay(5.seconds())
+            .max_retries(5);
+        let delays: Vec<_> = policy.delays().collect();
+        assert_eq!(
+            delays,
+            [
+                1.seconds(),
+                2.seconds(),
+                4.seconds(),
+                5.seconds(),
+                5.seconds()
+            ]
+        );
+
+        let policy = RetryPolicy::new(1.minutes()).max_delay(5.seconds());
+        assert_eq!(policy.delays().next(), Some(5.seconds()));
+
+        // Delays saturate instead of overflowing.
+        let policy = RetryPolicy::new(Duration::MAX).factor(10.0);
+        assert!(policy.delays().take(3).all(|d| d == Duration::MAX));
+    }
+
+    #[test]
+    fn test_jitter() {
+        let policy = RetryPolicy::new(1.seconds())
+            .max_delay(8.seconds())
+            .jitter(0.1)
+            .max_retries(5)
+            .seed(42);
+        let delays: Vec<_> = policy.delays().collect();
+        assert_eq!(delays.len(), 5);
+        assert_eq!(delays, policy.delays().collect::<Vec<_>>());
+
+        let expected = [
+            1.seconds(),
+            2.seconds(),
+            4.seconds(),
+            8.seconds(),
+            8.seconds(),
+        ];
+        for (delay, expected) in delays.iter().zip(expected) {
+            let max = expected.mul_f64(1.1).min(8.seconds());
+            assert!(
+                *delay >= expected.mul_f64(0.9) && *delay <= max,
+                "{delay:?}"
+            );
+        }
+        assert_ne!(delays, expected);
+
+        let other: Vec<_> = policy.clone().seed(7).delays().collect();
+        assert_ne!(delays, other);
+    }
+
+    #[test]
+    fn test_normalize_schedule() {
+        let mut delays = vec![
+            8.seconds(),
+            1.seconds(),
+            4.seconds(),
+            1.seconds(),
+            2.seconds(),
+            8.seconds(),
+        ];
+        normalize_schedule(&mut delays);
+        assert_eq!(delays, [1.seconds(), 2.seconds(), 4.seconds(), 8.seconds()]);
+
+        let mut empty = Vec::new();
+        normalize_schedule(&mut empty);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_cap_schedule() {
+        let mut delays = vec![1.seconds(), 2.seconds(), 4.seconds(), 8.seconds()];
+        cap_schedule(&mut delays, 3.seconds());
+        assert_eq!(delays, [1.seconds(), 2.seconds(), 3.seconds(), 3.seconds()]);
+
+        normalize_schedule(&mut delays);
+        assert_eq!(delays, [1.seconds(), 2.seconds(), 3.seconds()]);
+
+        cap_schedule(&mut delays, Duration::ZERO);
+        assert_eq!(delays, [Duration::ZERO; 3]);
+    }
+}
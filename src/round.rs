@@ -0,0 +1,321 @@
+//! Rounding and alignment of [`std::time::Duration`] values to whole ticks.
+
+use std::time::Duration;
+
+/// Rounds `d` up to the next whole multiple of `tick`.
+///
+/// Values that already are a multiple of `tick` are returned unchanged, as is
+/// `d` itself if `tick` is zero.
+///
+/// # Panics
+///
+/// Panics if the rounded value overflows [`Duration`].
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use easyduration::round::ceil_to_tick;
+///
+/// let tick = Duration::from_millis(10);
+/// assert_eq!(ceil_to_tick(Duration::from_millis(25), tick), Duration::from_millis(30));
+/// ```
+pub fn ceil_to_tick(d: Duration, tick: Duration) -> Duration {
+    let floor = floor_to_tick(d, tick);
+    if floor == d {
+        d
+    } else {
+        floor
+            .checked_add(tick)
+            .expect("overflow when rounding duration up to tick")
+    }
+}
+
+/// Rounds `d` down to the previous whole multiple of `tick`.
+///
+/// Values that already are a multiple of `tick` are returned unchanged, as is
+/// `d` itself if `tick` is zero.
+pub fn floor_to_tick(d: Duration, tick: Duration) -> Duration {
+    let tick_nanos = tick.as_nanos();
+    if tick_nanos == 0 {
+        return d;
+    }
+    let nanos = d.as_nanos();
+    crate::checked_from_nanos(nanos - nanos % tick_nanos).unwrap()
+}
+
+/// Returns the largest tick that evenly divides all `durations`, or `None` if
+/// the slice is empty.
+///
+/// This is the greatest common divisor of the durations at nanosecond
+/// resolution. Zero durations are ignored, unless all durations are zero in
+/// which case the result is zero.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use easyduration::{round::common_tick, EasyDuration};
+///
+/// let tick = common_tick(&[1.seconds(), Duration::from_millis(1500)]);
+/// assert_eq!(tick, Some(Duration::from_millis(500)));
+/// ```
+pub fn common_tick(durations: &[Duration]) -> Option<Duration> {
+    if durations.is_empty() {
+        return None;
+    }
+    let nanos = durations.iter().map(Duration::as_nanos).fold(0, gcd);
+    crate::checked_from_nanos(nanos)
+}
+
+/// Returns how much to add to `d` to reach the next whole multiple of `unit`.
+///
+/// Returns zero if `d` already is a multiple of `unit` or if `unit` is zero.
+/// Unlike [`ceil_to_tick`] this never overflows, even if `d` rounded up
+/// wouldn't fit into a [`Duration`].
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{round::until_next_whole, EasyDuration};
+///
+/// assert_eq!(until_next_whole(90.seconds(), 1.minutes()), 30.seconds());
+/// ```
+pub fn until_next_whole(d: Duration, unit: Duration) -> Duration {
+    let unit_nanos = unit.as_nanos();
+    if unit_nanos == 0 {
+        return Duration::ZERO;
+    }
+    match d.as_nanos() % unit_nanos {
+        0 => Duration::ZERO,
+        rem => crate::checked_from_nanos(unit_nanos - rem).unwrap(),
+    }
+}
+
+/// Returns the number of whole `unit`s in `d` and the remainder.
+///
+/// A zero `unit` yields `(0, d)`. The count saturates at [`u64::MAX`], which
+/// can only happen for units below one second, while the remainder is always
+/// exact.
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{round::div_rem_unit, EasyDuration};
+///
+/// assert_eq!(div_rem_unit(90.minutes(), 1.hours()), (1, 30.minutes()));
+/// ```
+pub fn div_rem_unit(d: Duration, unit: Duration) -> (u64, Duration) {
+    let unit_nanos = unit.as_nanos();
+    if unit_nanos == 0 {
+        return (0, d);
+    }
+    let nanos = d.as_nanos();
+    let count = (nanos / unit_nanos).try_into().unwrap_or(u64::MAX);
+    let rem = crate::checked_from_nanos(nanos % unit_nanos).unwrap();
+    (count, rem)
+}
+
+/// Returns the number of `unit`s in `d`, rounded to the nearest whole number
+/// with halves rounding up.
+///
+/// A zero `unit` yields zero. The count saturates at [`u64::MAX`].
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{round::round_to_unit_count, EasyDuration};
+///
+/// assert_eq!(round_to_unit_count(100.seconds(), 1.minutes()), 2);
+/// ```
+pub fn round_to_unit_count(d: Duration, unit: Duration) -> u64 {
+    let unit_nanos = unit.as_nanos();
+    if unit_nanos == 0 {
+        return 0;
+    }
+    let count = (d.as_nanos() + unit_nanos / 2) / unit_nanos;
+    count.try_into().unwrap_or(u64::MAX)
+}
+
+/// Returns the value of `allowed` closest to `d`, or `None` if `allowed` is
+/// empty.
+///
+/// `allowed` must be sorted in ascending order. Ties go to the larger value.
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{round::snap_to, EasyDuration};
+///
+/// let ttls = [5.minutes(), 10.minutes(), 15.minutes()];
+/// assert_eq!(snap_to(7.minutes(), &ttls), Some(5.minutes()));
+/// ```
+pub fn snap_to(d: Duration, allowed: &[Duration]) -> Option<Duration> {
+    let index = allowed.partition_point(|value| *value < d);
+    let above = allowed.get(index).copied();
+    let below = index.checked_sub(1).map(|i| allowed[i]);
+    match (below, above) {
+        (Some(below), Some(above)) if d - below < above - d => Some(below),
+        (_, Some(above)) => Some(above),
+        (below, None) => below,
+    }
+}
+
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EasyDuration;
+
+    #[test]
+    fn test_ceil_floor_to_tick() {
+        let ms = Duration::from_millis;
+        let tick = ms(10);
+
+        assert_eq!(ceil_to_tick(ms(25), tick), ms(30));
+        assert_eq!(ceil_to_tick(ms(30), tick), ms(30));
+        assert_eq!(ceil_to_tick(ms(1), tick), ms(10));
+        assert_eq!(ceil_to_tick(Duration::ZERO, tick), Duration::ZERO);
+
+        assert_eq!(floor_to_tick(ms(25), tick), ms(20));
+        assert_eq!(floor_to_tick(ms(30), tick), ms(30));
+        assert_eq!(floor_to_tick(ms(9), tick), Duration::ZERO);
+
+        assert_eq!(floor_to_tick(90.seconds(), 1.minutes()), 1.minutes());
+        assert_eq!(ceil_to_tick(90.seconds(), 1.minutes()), 2.minutes());
+
+        // A zero tick leaves the input unchanged.
+        assert_eq!(ceil_to_tick(ms(25), Duration::ZERO), ms(25));
+        assert_eq!(floor_to_tick(ms(25), Duration::ZERO), ms(25));
+    }
+
+    #[test]
+    fn test_common_tick() {
+        let ms = Duration::from_millis;
+
+        assert_eq!(common_tick(&[]), None);
+        assert_eq!(common_tick(&[1.seconds(), ms(1500)]), Some(ms(500)));
+        assert_eq!(common_tick(&[ms(300), ms(450), ms(600)]), Some(ms(150)));
+        assert_eq!(common_tick(&[7.seconds()]), Some(7.seconds()));
+        assert_eq!(common_tick(&[1.minutes(), 1.hours()]), Some(1.minutes()));
+        assert_eq!(
+            common_tick(&[Duration::from_nanos(3), 1.seconds()]),
+            Some(Duration::from_nanos(1))
+        );
+        assert_eq!(common_tick(&[Duration::ZERO, ms(40)]), Some(ms(40)));
+        assert_eq!(common_tick(&[Duration::ZERO]), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_until_next_whole() {
+        let ms = Duration::from_millis;
+
+        assert_eq!(until_next_whole(90.seconds(), 1.minutes()), 30.seconds());
+        assert_eq!(until_next_whole(2.minutes(), 1.minutes()), Duration::ZERO);
+        assert_eq!(
+            until_next_whole(Duration::ZERO, 1.minutes()),
+            Duration::ZERO
+        );
+        assert_eq!(until_next_whole(ms(1), 1.seconds()), ms(999));
+        assert_eq!(until_next_whole(ms(25), ms(10)), ms(5));
+        assert_eq!(
+            until_next_whole(90.seconds(), Duration::ZERO),
+            Duration::ZERO
+        );
+        assert_eq!(
+            until_next_whole(Duration::MAX, 1.seconds()),
+            Duration::from_nanos(1)
+        );
+        assert_eq!(
+            ms(25) + until_next_whole(ms(25), ms(10)),
+            ceil_to_tick(ms(25), ms(10))
+        );
+    }
+
+    #[test]
+    fn test_div_rem_unit() {
+        assert_eq!(div_rem_unit(90.minutes(), 1.hours()), (1, 30.minutes()));
+        assert_eq!(div_rem_unit(2.hours(), 1.hours()), (2, Duration::ZERO));
+        assert_eq!(div_rem_unit(59.minutes(), 1.hours()), (0, 59.minutes()));
+        assert_eq!(
+            div_rem_unit(Duration::from_millis(2500), 1.seconds()),
+            (2, Duration::from_millis(500))
+        );
+        assert_eq!(
+            div_rem_unit(Duration::ZERO, 1.seconds()),
+            (0, Duration::ZERO)
+        );
+        assert_eq!(
+            div_rem_unit(90.minutes(), Duration::ZERO),
+            (0, 90.minutes())
+        );
+
+        assert_eq!(
+            div_rem_unit(Duration::MAX, 1.seconds()),
+            (u64::MAX, Duration::from_nanos(999_999_999))
+        );
+        assert_eq!(
+            div_rem_unit(Duration::MAX, Duration::from_nanos(1)),
+            (u64::MAX, Duration::ZERO)
+        );
+    }
+
+    #[test]
+    fn test_round_to_unit_count() {
+        let minute = 1.minutes();
+
+        assert_eq!(round_to_unit_count(100.seconds(), minute), 2);
+        assert_eq!(round_to_unit_count(89.seconds(), minute), 1);
+        assert_eq!(round_to_unit_count(90.seconds(), minute), 2);
+        assert_eq!(round_to_unit_count(29.seconds(), minute), 0);
+        assert_eq!(round_to_unit_count(30.seconds(), minute), 1);
+        assert_eq!(round_to_unit_count(2.minutes(), minute), 2);
+        assert_eq!(round_to_unit_count(Duration::ZERO, minute), 0);
+        assert_eq!(round_to_unit_count(2.minutes(), Duration::ZERO), 0);
+        // Odd units round half up too.
+        assert_eq!(
+            round_to_unit_count(Duration::from_nanos(2), Duration::from_nanos(3)),
+            1
+        );
+        assert_eq!(
+            round_to_unit_count(Duration::from_nanos(1), Duration::from_nanos(3)),
+            0
+        );
+
+        assert_eq!(
+            round_to_unit_count(Duration::MAX, Duration::from_nanos(1)),
+            u64::MAX
+        );
+        assert_eq!(round_to_unit_count(Duration::MAX, 1.seconds()), u64::MAX);
+    }
+
+    #[test]
+    fn test_snap_to() {
+        let allowed = [5.minutes(), 10.minutes(), 15.minutes()];
+
+        assert_eq!(snap_to(7.minutes(), &allowed), Some(5.minutes()));
+        assert_eq!(snap_to(8.minutes(), &allowed), Some(10.minutes()));
+        assert_eq!(snap_to(10.minutes(), &allowed), Some(10.minutes()));
+        // Ties go to the larger value.
+        assert_eq!(snap_to(450.seconds(), &allowed), Some(10.minutes()));
+        assert_eq!(snap_to(750.seconds(), &allowed), Some(15.minutes()));
+
+        assert_eq!(snap_to(Duration::ZERO, &allowed), Some(5.minutes()));
+        assert_eq!(snap_to(1.hours(), &allowed), Some(15.minutes()));
+        assert_eq!(snap_to(Duration::MAX, &allowed), Some(15.minutes()));
+        assert_eq!(snap_to(1.hours(), &[1.seconds()]), Some(1.seconds()));
+        assert_eq!(snap_to(1.hours(), &[]), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow")]
+    fn test_ceil_to_tick_overflow() {
+        ceil_to_tick(Duration::MAX, 7.seconds());
+    }
+}
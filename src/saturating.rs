@@ -0,0 +1,116 @@
+//! Saturating [`std::time::Duration`] arithmetic with the standard operators.
+
+use std::{
+    ops::{Add, Mul, Sub},
+    time::Duration,
+};
+
+/// A [`Duration`] whose arithmetic operators saturate instead of panicking.
+///
+/// Subtraction saturates at zero, addition and multiplication at
+/// [`Duration::MAX`].
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use easyduration::{saturating::SaturatingDuration, EasyDuration};
+///
+/// let d = SaturatingDuration(1.seconds()) - 5.seconds();
+/// assert_eq!(Duration::from(d), Duration::ZERO);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SaturatingDuration(pub Duration);
+
+impl From<Duration> for SaturatingDuration {
+    fn from(d: Duration) -> Self {
+        Self(d)
+    }
+}
+
+impl From<SaturatingDuration> for Duration {
+    fn from(d: SaturatingDuration) -> Self {
+        d.0
+    }
+}
+
+impl Add for SaturatingDuration {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self + rhs.0
+    }
+}
+
+impl Add<Duration> for SaturatingDuration {
+    type Output = Self;
+
+    fn add(self, rhs: Duration) -> Self {
+        Self(self.0.saturating_add(rhs))
+    }
+}
+
+impl Sub for SaturatingDuration {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self - rhs.0
+    }
+}
+
+impl Sub<Duration> for SaturatingDuration {
+    type Output = Self;
+
+    fn sub(self, rhs: Duration) -> Self {
+        Self(self.0.saturating_sub(rhs))
+    }
+}
+
+impl Mul<u32> for SaturatingDuration {
+    type Output = Self;
+
+    fn mul(self, rhs: u32) -> Self {
+        Self(self.0.saturating_mul(rhs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EasyDuration;
+
+    #[test]
+    fn test_saturating_ops() {
+        let d = SaturatingDuration(5.seconds());
+
+        assert_eq!(d - 2.seconds(), SaturatingDuration(3.seconds()));
+        assert_eq!(d - 10.seconds(), SaturatingDuration(Duration::ZERO));
+        assert_eq!(
+            d - SaturatingDuration(10.seconds()),
+            SaturatingDuration::default()
+        );
+
+        assert_eq!(d + 2.seconds(), SaturatingDuration(7.seconds()));
+        assert_eq!(
+            d + SaturatingDuration(Duration::MAX),
+            SaturatingDuration(Duration::MAX)
+        );
+        assert_eq!(
+            SaturatingDuration(Duration::MAX) + 1.seconds(),
+            SaturatingDuration(Duration::MAX)
+        );
+
+        assert_eq!(d * 3, SaturatingDuration(15.seconds()));
+        assert_eq!(
+            SaturatingDuration(Duration::MAX) * 2,
+            SaturatingDuration(Duration::MAX)
+        );
+
+        let plain: Duration = (d - 1.seconds()).into();
+        assert_eq!(plain, 4.seconds());
+        assert_eq!(
+            SaturatingDuration::from(plain),
+            SaturatingDuration(4.seconds())
+        );
+    }
+}
@@ -0,0 +1,246 @@
+//! Scheduling helpers operating on [`std::time::SystemTime`] values.
+
+use std::time::{Duration, SystemTime};
+
+/// Returns how long the intervals `[a_start, a_start + a_dur)` and
+/// `[b_start, b_start + b_dur)` overlap.
+///
+/// Disjoint or merely touching intervals overlap by zero.
+///
+/// # Panics
+///
+/// Panics if the end of an interval can't be represented as a
+/// [`SystemTime`].
+pub fn overlap(
+    a_start: SystemTime,
+    a_dur: Duration,
+    b_start: SystemTime,
+    b_dur: Duration,
+) -> Duration {
+    let start = a_start.max(b_start);
+    let end = (a_start + a_dur).min(b_start + b_dur);
+    end.duration_since(start).unwrap_or(Duration::ZERO)
+}
+
+/// Returns the first occurrence of a schedule starting at `start` and
+/// repeating every `period` that is strictly after `now`.
+///
+/// If `now` is before `start`, that is `start` itself. A zero `period` never
+/// repeats, so `start` is returned as well.
+///
+/// # Panics
+///
+/// Panics if the occurrence can't be represented as a [`SystemTime`].
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::SystemTime;
+/// use easyduration::{schedule::next_occurrence, DurationExt, EasyDuration};
+///
+/// let start = SystemTime::UNIX_EPOCH;
+/// let now = 90.minutes().after(start);
+/// assert_eq!(next_occurrence(start, 1.hours(), now), 2.hours().after(start));
+/// ```
+pub fn next_occurrence(start: SystemTime, period: Duration, now: SystemTime) -> SystemTime {
+    let period_nanos = period.as_nanos();
+    let Ok(elapsed) = now.duration_since(start) else {
+        return start;
+    };
+    if period_nanos == 0 {
+        return start;
+    }
+    let count = elapsed.as_nanos() / period_nanos + 1;
+    let offset = count
+        .checked_mul(period_nanos)
+        .and_then(crate::checked_from_nanos)
+        .expect("overflow when computing next occurrence");
+    start + offset
+}
+
+/// Returns the time elapsed since the most recent UTC midnight before `t`.
+///
+/// This is computed from the UNIX timestamp of `t` modulo one day, so like
+/// UNIX time it ignores leap seconds. Times before the UNIX epoch are
+/// supported.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::SystemTime;
+/// use easyduration::{schedule::since_midnight_utc, DurationExt, EasyDuration};
+///
+/// // 2023-11-14T22:13:20Z
+/// let t = 1_700_000_000.seconds().after(SystemTime::UNIX_EPOCH);
+/// assert_eq!(since_midnight_utc(t), 22.hours() + 13.minutes() + 20.seconds());
+/// ```
+pub fn since_midnight_utc(t: SystemTime) -> Duration {
+    const DAY_NANOS: u128 = 86_400 * 1_000_000_000;
+    let nanos = match t.duration_since(SystemTime::UNIX_EPOCH) {
+        Ok(since) => since.as_nanos() % DAY_NANOS,
+        Err(err) => (DAY_NANOS - err.duration().as_nanos() % DAY_NANOS) % DAY_NANOS,
+    };
+    crate::checked_from_nanos(nanos).unwrap()
+}
+
+/// Returns `true` if a job repeating every `d` can be written as a simple
+/// periodic cron expression.
+///
+/// This is the case if `d` is one of:
+///
+/// * a whole number of seconds that divides a minute, like `*/15` seconds,
+/// * a whole number of minutes that divides an hour, like `*/10` minutes,
+/// * a whole number of hours that divides a day, like `*/6` hours,
+/// * exactly one day.
+///
+/// Intervals like 90 seconds or 7 hours would drift against the cron fields
+/// and are rejected, as are zero and sub-second intervals.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use easyduration::{schedule::is_cron_expressible, EasyDuration};
+///
+/// assert!(is_cron_expressible(30.seconds()));
+/// assert!(!is_cron_expressible(90.seconds()));
+/// assert!(!is_cron_expressible(Duration::from_millis(45)));
+/// ```
+pub fn is_cron_expressible(d: Duration) -> bool {
+    if d.subsec_nanos() != 0 {
+        return false;
+    }
+    let secs = d.as_secs();
+    let divides = |unit: u64, period: u64| {
+        secs.is_multiple_of(unit)
+            && (1..=period).contains(&(secs / unit))
+            && period.is_multiple_of(secs / unit)
+    };
+    divides(1, 60) || divides(60, 60) || divides(60 * 60, 24) || secs == 60 * 60 * 24
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DurationExt, EasyDuration};
+
+    #[test]
+    fn test_overlap() {
+        let t0 = SystemTime::UNIX_EPOCH + 1_700_000_000.seconds();
+        let at = |minutes: u64| minutes.minutes().after(t0);
+
+        // Partial overlap, in both argument orders.
+        assert_eq!(
+            overlap(at(0), 60.minutes(), at(30), 60.minutes()),
+            30.minutes()
+        );
+        assert_eq!(
+            overlap(at(30), 60.minutes(), at(0), 60.minutes()),
+            30.minutes()
+        );
+        // One interval contains the other.
+        assert_eq!(
+            overlap(at(0), 60.minutes(), at(10), 5.minutes()),
+            5.minutes()
+        );
+        // Touching intervals.
+        assert_eq!(
+            overlap(at(0), 60.minutes(), at(60), 60.minutes()),
+            Duration::ZERO
+        );
+        // Disjoint intervals.
+        assert_eq!(
+            overlap(at(0), 10.minutes(), at(60), 60.minutes()),
+            Duration::ZERO
+        );
+        assert_eq!(
+            overlap(at(60), 10.minutes(), at(0), 10.minutes()),
+            Duration::ZERO
+        );
+        // Identical intervals.
+        assert_eq!(
+            overlap(at(0), 15.minutes(), at(0), 15.minutes()),
+            15.minutes()
+        );
+    }
+
+    #[test]
+    fn test_next_occurrence() {
+        let start = SystemTime::UNIX_EPOCH + 1_700_000_000.seconds();
+        let at = |secs: u64| secs.seconds().after(start);
+        let period = 10.minutes();
+
+        assert_eq!(next_occurrence(start, period, at(25 * 60)), at(30 * 60));
+        assert_eq!(next_occurrence(start, period, at(1)), at(600));
+        // Strictly after now, even if now is an occurrence.
+        assert_eq!(next_occurrence(start, period, at(600)), at(1200));
+        assert_eq!(next_occurrence(start, period, start), at(600));
+
+        // Now before start.
+        let before = 5.minutes().before(start);
+        assert_eq!(next_occurrence(start, period, before), start);
+
+        assert_eq!(next_occurrence(start, Duration::ZERO, at(100)), start);
+        assert_eq!(
+            next_occurrence(start, Duration::from_millis(1500), at(4)),
+            start + Duration::from_millis(4500)
+        );
+    }
+
+    #[test]
+    fn test_since_midnight_utc() {
+        let epoch = SystemTime::UNIX_EPOCH;
+
+        assert_eq!(since_midnight_utc(epoch), Duration::ZERO);
+        // 2023-11-14T22:13:20Z
+        assert_eq!(
+            since_midnight_utc(1_700_000_000.seconds().after(epoch)),
+            22.hours() + 13.minutes() + 20.seconds()
+        );
+        // 2000-01-01T09:00:00.5Z
+        let nine = (946_684_800 + 9 * 3600).seconds().after(epoch) + Duration::from_millis(500);
+        assert_eq!(
+            since_midnight_utc(nine),
+            9.hours() + Duration::from_millis(500)
+        );
+        assert_eq!(since_midnight_utc(1.days().after(epoch)), Duration::ZERO);
+
+        // 1969-12-31T23:00:00Z and 1969-12-31T00:00:00Z
+        assert_eq!(since_midnight_utc(1.hours().before(epoch)), 23.hours());
+        assert_eq!(since_midnight_utc(1.days().before(epoch)), Duration::ZERO);
+        assert_eq!(
+            since_midnight_utc(Duration::from_nanos(1).before(epoch)),
+            1.days() - Duration::from_nanos(1)
+        );
+    }
+
+    #[test]
+    fn test_is_cron_expressible() {
+        for d in [
+            1.seconds(),
+            30.seconds(),
+            20.seconds(),
+            1.minutes(),
+            15.minutes(),
+            1.hours(),
+            6.hours(),
+            12.hours(),
+            1.days(),
+        ] {
+            assert!(is_cron_expressible(d), "{d:?}");
+        }
+        for d in [
+            Duration::ZERO,
+            Duration::from_millis(45),
+            Duration::from_millis(1500),
+            7.seconds(),
+            90.seconds(),
+            7.minutes(),
+            7.hours(),
+            2.days(),
+            Duration::MAX,
+        ] {
+            assert!(!is_cron_expressible(d), "{d:?}");
+        }
+    }
+}
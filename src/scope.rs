@@ -0,0 +1,170 @@
+//! Accumulating the time spent in a possibly reentrant scope.
+
+use std::{
+    cell::Cell,
+    time::{Duration, Instant},
+};
+
+use crate::clock::{Clock, SystemClock};
+
+/// Accumulates the total time spent in a scope across multiple entries.
+///
+/// Each [`enter`](ScopeTimer::enter) returns a [`ScopeGuard`] that ends the
+/// span when dropped. The timer is reentrant: entering it again while a guard
+/// is alive, for example from a recursive call, only increases the nesting
+/// depth. The span keeps running until the outermost guard is dropped, so
+/// overlapping entries are never counted twice.
+///
+/// [`ScopeTimer::new`] times with the [`SystemClock`]. To control time, for
+/// example with a [`MockClock`](crate::clock::MockClock) in tests, pass any
+/// other [`Clock`] to [`with_clock`](ScopeTimer::with_clock).
+///
+/// The state lives in [`Cell`]s, so entering only needs a shared reference,
+/// but a timer can't be shared between threads.
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{clock::MockClock, scope::ScopeTimer, EasyDuration};
+///
+/// let clock = MockClock::new();
+/// let timer = ScopeTimer::with_clock(clock.clone());
+/// {
+///     let _outer = timer.enter();
+///     clock.advance(1.seconds());
+///     let _inner = timer.enter();
+///     clock.advance(1.seconds());
+/// }
+/// assert_eq!(timer.total(), 2.seconds());
+/// ```
+#[derive(Debug, Default)]
+pub struct ScopeTimer<C: Clock = SystemClock> {
+    clock: C,
+    depth: Cell<usize>,
+    entered_at: Cell<Option<Instant>>,
+    total: Cell<Duration>,
+}
+
+impl ScopeTimer {
+    /// Creates a timer with nothing accumulated yet.
+    pub fn new() -> Self {
+        Self::with_clock(SystemClock)
+    }
+}
+
+impl<C: Clock> ScopeTimer<C> {
+    /// Creates a timer with nothing accumulated yet that reads the current
+    /// time from `clock`.
+    pub fn with_clock(clock: C) -> Self {
+        Self {
+            clock,
+            depth: Cell::new(0),
+            entered_at: Cell::new(None),
+            total: Cell::new(Duration::ZERO),
+        }
+    }
+
+    /// Enters the scope until the returned guard is dropped.
+    pub fn enter(&self) -> ScopeGuard<'_, C> {
+        if self.depth.get() == 0 {
+            self.entered_at.set(Some(self.clock.now()));
+        }
+        self.depth.set(self.depth.get() + 1);
+        ScopeGuard { timer: self }
+    }
+
+    /// Returns the total time spent in the scope, including the currently
+    /// open span if the scope is entered.
+    ///
+    /// Saturates at [`Duration::MAX`].
+    pub fn total(&self) -> Duration {
+        self.total.get().saturating_add(self.open_span())
+    }
+
+    fn open_span(&self) -> Duration {
+        match self.entered_at.get() {
+            Some(entered_at) => self.clock.now().saturating_duration_since(entered_at),
+            None => Duration::ZERO,
+        }
+    }
+
+    fn exit(&self) {
+        let depth = self.depth.get() - 1;
+        self.depth.set(depth);
+        if depth == 0 {
+            self.total.set(self.total());
+            self.entered_at.set(None);
+        }
+    }
+}
+
+/// Guard returned by [`ScopeTimer::enter`] that exits the scope when dropped.
+#[derive(Debug)]
+#[must_use = "the scope is exited as soon as the guard is dropped"]
+pub struct ScopeGuard<'a, C: Clock = SystemClock> {
+    timer: &'a ScopeTimer<C>,
+}
+
+impl<C: Clock> Drop for ScopeGuard<'_, C> {
+    fn drop(&mut self) {
+        self.timer.exit();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{clock::MockClock, EasyDuration};
+
+    #[test]
+    fn test_scope_timer() {
+        let clock = MockClock::new();
+        let timer = ScopeTimer::with_clock(clock.clone());
+        assert_eq!(timer.total(), Duration::ZERO);
+
+        {
+            let _outer = timer.enter();
+            clock.advance(1.seconds());
+            {
+                let _inner = timer.enter();
+                clock.advance(2.seconds());
+                let _innermost = timer.enter();
+                clock.advance(3.seconds());
+            }
+            // The open span is included.
+            assert_eq!(timer.total(), 6.seconds());
+            clock.advance(1.seconds());
+        }
+        assert_eq!(timer.total(), 7.seconds());
+
+        // Time outside the scope isn't counted.
+        clock.advance(1.hours());
+        assert_eq!(timer.total(), 7.seconds());
+
+        // Separate entries accumulate.
+        let guard = timer.enter();
+        clock.advance(3.seconds());
+        drop(guard);
+        assert_eq!(timer.total(), 10.seconds());
+    }
+
+    #[test]
+    fn test_scope_timer_recursion() {
+        fn recurse(timer: &ScopeTimer<MockClock>, clock: &MockClock, depth: u32) {
+            let _guard = timer.enter();
+            clock.advance(1.seconds());
+            if depth > 0 {
+                recurse(timer, clock, depth - 1);
+            }
+        }
+
+        let clock = MockClock::new();
+        let timer = ScopeTimer::with_clock(clock.clone());
+        recurse(&timer, &clock, 4);
+        assert_eq!(timer.total(), 5.seconds());
+
+        let timer = ScopeTimer::new();
+        drop(timer.enter());
+        assert!(timer.total() < 1.seconds());
+    }
+}
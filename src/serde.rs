@@ -0,0 +1,186 @@
+//! Helpers for (de)serializing durations with [`serde`](https://docs.rs/serde).
+//!
+//! Requires the `serde` feature.
+
+use std::{fmt, time::Duration};
+
+use ::serde::de::{self, Deserializer, Visitor};
+
+/// (De)serializes a [`Duration`] as an ISO 8601 string like `"PT1H30M"`.
+///
+/// Use it with `#[serde(with = "easyduration::serde::iso8601")]`. See
+/// [`to_iso8601`](crate::format::to_iso8601) and
+/// [`parse_iso8601`](crate::parse::parse_iso8601) for the format.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use easyduration::EasyDuration;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Lease {
+///     #[serde(with = "easyduration::serde::iso8601")]
+///     ttl: Duration,
+/// }
+///
+/// let json = serde_json::to_string(&Lease { ttl: 90.minutes() }).unwrap();
+/// assert_eq!(json, r#"{"ttl":"PT1H30M"}"#);
+/// ```
+pub mod iso8601 {
+    use std::{fmt, time::Duration};
+
+    use ::serde::{
+        de::{self, Visitor},
+        Deserializer, Serializer,
+    };
+
+    /// Serializes `d` as an ISO 8601 string.
+    pub fn serialize<S: Serializer>(d: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&crate::format::to_iso8601(*d))
+    }
+
+    /// Deserializes a [`Duration`] from an ISO 8601 string.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        deserializer.deserialize_str(Iso8601Visitor)
+    }
+
+    struct Iso8601Visitor;
+
+    impl Visitor<'_> for Iso8601Visitor {
+        type Value = Duration;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("an ISO 8601 duration string")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Duration, E> {
+            crate::parse::parse_iso8601(v).map_err(E::custom)
+        }
+    }
+}
+
+/// Deserializes a [`Duration`] from either a number of seconds or a duration
+/// string.
+///
+/// Numbers may be fractional, like `1.5`. Strings are parsed with
+/// [`parse()`](crate::parse()), like `"1m30s"`. Use it with
+/// `#[serde(deserialize_with = "easyduration::serde::number_or_string")]`.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use easyduration::EasyDuration;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Config {
+///     #[serde(deserialize_with = "easyduration::serde::number_or_string")]
+///     timeout: Duration,
+/// }
+///
+/// let config: Config = serde_json::from_str(r#"{"timeout": "30s"}"#).unwrap();
+/// assert_eq!(config.timeout, 30.seconds());
+/// let config: Config = serde_json::from_str(r#"{"timeout": 30}"#).unwrap();
+/// assert_eq!(config.timeout, 30.seconds());
+/// ```
+pub fn number_or_string<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(NumberOrStringVisitor)
+}
+
+struct NumberOrStringVisitor;
+
+impl Visitor<'_> for NumberOrStringVisitor {
+    type Value = Duration;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a number of seconds or a duration string")
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Duration, E> {
+        Ok(Duration::from_secs(v))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Duration, E> {
+        u64::try_from(v)
+            .map(Duration::from_secs)
+            .map_err(|_| E::invalid_value(de::Unexpected::Signed(v), &self))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Duration, E> {
+        Duration::try_from_secs_f64(v)
+            .map_err(|_| E::invalid_value(de::Unexpected::Float(v), &self))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Duration, E> {
+        crate::parse(v).map_err(E::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::serde::{Deserialize, Serialize};
+
+    use super::*;
+    use crate::EasyDuration;
+
+    #[derive(Debug, Deserialize)]
+    struct Config {
+        #[serde(deserialize_with = "number_or_string")]
+        timeout: Duration,
+    }
+
+    fn timeout(json: &str) -> Result<Duration, serde_json::Error> {
+        serde_json::from_str::<Config>(json).map(|config| config.timeout)
+    }
+
+    #[test]
+    fn test_number_or_string() {
+        assert_eq!(timeout(r#"{"timeout": 30}"#).unwrap(), 30.seconds());
+        assert_eq!(timeout(r#"{"timeout": "30s"}"#).unwrap(), 30.seconds());
+        assert_eq!(
+            timeout(r#"{"timeout": 90}"#).unwrap(),
+            timeout(r#"{"timeout": "1m30s"}"#).unwrap()
+        );
+        assert_eq!(
+            timeout(r#"{"timeout": 1.5}"#).unwrap(),
+            Duration::from_millis(1500)
+        );
+
+        assert!(timeout(r#"{"timeout": -1}"#).is_err());
+        assert!(timeout(r#"{"timeout": -1.5}"#).is_err());
+        assert!(timeout(r#"{"timeout": true}"#).is_err());
+        let err = timeout(r#"{"timeout": "30x"}"#).unwrap_err();
+        assert!(err.to_string().contains("unknown unit 'x'"), "{err}");
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Lease {
+        #[serde(with = "iso8601")]
+        ttl: Duration,
+    }
+
+    #[test]
+    fn test_iso8601() {
+        for (ttl, json) in [
+            (90.minutes(), r#"{"ttl":"PT1H30M"}"#),
+            (Duration::ZERO, r#"{"ttl":"PT0S"}"#),
+            (Duration::from_millis(1500), r#"{"ttl":"PT1.5S"}"#),
+            (2.days() + 5.seconds(), r#"{"ttl":"PT48H5S"}"#),
+        ] {
+            let lease = Lease { ttl };
+            assert_eq!(serde_json::to_string(&lease).unwrap(), json);
+            assert_eq!(serde_json::from_str::<Lease>(json).unwrap(), lease);
+        }
+
+        let lease: Lease = serde_json::from_str(r#"{"ttl":"P1DT2H"}"#).unwrap();
+        assert_eq!(lease.ttl, 26.hours());
+        assert!(serde_json::from_str::<Lease>(r#"{"ttl":"1h"}"#).is_err());
+        assert!(serde_json::from_str::<Lease>(r#"{"ttl":60}"#).is_err());
+    }
+}
@@ -0,0 +1,140 @@
+//! A [`std::time::Duration`] with a sign.
+
+use std::time::Duration;
+
+/// A duration that may be negative, like the difference of two timestamps.
+///
+/// This is a [`Duration`] magnitude paired with a sign. Zero is never
+/// negative.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SignedDuration {
+    magnitude: Duration,
+    negative: bool,
+}
+
+impl SignedDuration {
+    /// The zero duration.
+    pub const ZERO: Self = Self {
+        magnitude: Duration::ZERO,
+        negative: false,
+    };
+
+    /// Creates a signed duration from a `magnitude` and a sign.
+    pub const fn new(magnitude: Duration, negative: bool) -> Self {
+        Self {
+            magnitude,
+            negative: negative && !magnitude.is_zero(),
+        }
+    }
+
+    /// Creates a positive signed duration.
+    pub const fn positive(magnitude: Duration) -> Self {
+        Self::new(magnitude, false)
+    }
+
+    /// Creates a negative signed duration.
+    pub const fn negative(magnitude: Duration) -> Self {
+        Self::new(magnitude, true)
+    }
+
+    /// Returns the absolute length of the duration.
+    pub const fn magnitude(&self) -> Duration {
+        self.magnitude
+    }
+
+    /// Returns `true` if the duration is below zero.
+    pub const fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Returns the total number of nanoseconds, negative if the duration is.
+    pub fn as_nanos(&self) -> i128 {
+        to_signed_nanos(self.magnitude, self.negative)
+    }
+}
+
+/// Returns the total number of nanoseconds in `d`, negated if `negative`.
+///
+/// Every [`Duration`] fits into an [`i128`], so this is lossless.
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{signed::to_signed_nanos, EasyDuration};
+///
+/// assert_eq!(to_signed_nanos(2.seconds(), true), -2_000_000_000);
+/// ```
+pub fn to_signed_nanos(d: Duration, negative: bool) -> i128 {
+    // At most ~1.8e28, far below i128::MAX.
+    let nanos = d.as_nanos() as i128;
+    if negative {
+        -nanos
+    } else {
+        nanos
+    }
+}
+
+/// Creates a [`SignedDuration`] from a signed number of nanoseconds.
+///
+/// # Panics
+///
+/// Panics if the magnitude of `nanos` overflows [`Duration`].
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::signed::from_signed_nanos;
+///
+/// let d = from_signed_nanos(-1_500_000_000);
+/// assert!(d.is_negative());
+/// assert_eq!(d.magnitude().as_millis(), 1500);
+/// ```
+pub fn from_signed_nanos(nanos: i128) -> SignedDuration {
+    let magnitude = crate::checked_from_nanos(nanos.unsigned_abs())
+        .expect("overflow when constructing signed duration");
+    SignedDuration::new(magnitude, nanos < 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EasyDuration;
+
+    #[test]
+    fn test_signed_nanos() {
+        assert_eq!(to_signed_nanos(1.seconds(), false), 1_000_000_000);
+        assert_eq!(to_signed_nanos(1.seconds(), true), -1_000_000_000);
+        assert_eq!(to_signed_nanos(Duration::ZERO, true), 0);
+
+        for nanos in [0, 1, -1, 1_500_000_000, -1_500_000_000, -86_400_000_000_123] {
+            assert_eq!(from_signed_nanos(nanos).as_nanos(), nanos);
+        }
+
+        let max = to_signed_nanos(Duration::MAX, true);
+        assert_eq!(
+            from_signed_nanos(max),
+            SignedDuration::negative(Duration::MAX)
+        );
+        assert_eq!(from_signed_nanos(max).as_nanos(), max);
+
+        assert_eq!(
+            from_signed_nanos(-3),
+            SignedDuration::negative(Duration::from_nanos(3))
+        );
+        assert_eq!(
+            from_signed_nanos(3),
+            SignedDuration::positive(Duration::from_nanos(3))
+        );
+        assert!(!from_signed_nanos(0).is_negative());
+        assert_eq!(
+            SignedDuration::negative(Duration::ZERO),
+            SignedDuration::ZERO
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow")]
+    fn test_from_signed_nanos_overflow() {
+        from_signed_nanos(i128::MIN);
+    }
+}
@@ -0,0 +1,161 @@
+//! Splitting a [`std::time::Duration`] into parts.
+
+use std::time::Duration;
+
+/// Splits `total` into shares proportional to `weights`.
+///
+/// Each share is rounded down to the nanosecond and the last share absorbs the
+/// rounding remainder, so the shares always sum to exactly `total`. If all
+/// weights are zero, `total` is split into equal shares. An empty `weights`
+/// slice yields no shares.
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{split::split_weighted, EasyDuration};
+///
+/// let shares = split_weighted(10.seconds(), &[1, 3, 1]);
+/// assert_eq!(shares, [2.seconds(), 6.seconds(), 2.seconds()]);
+/// ```
+pub fn split_weighted(total: Duration, weights: &[u32]) -> Vec<Duration> {
+    let Some((_, init)) = weights.split_last() else {
+        return Vec::new();
+    };
+
+    let total_nanos = total.as_nanos();
+    let weight_sum: u128 = weights.iter().map(|w| u128::from(*w)).sum();
+    let share_nanos = |weight: u32| {
+        (total_nanos * u128::from(weight))
+            .checked_div(weight_sum)
+            // All weights are zero, split equally.
+            .unwrap_or(total_nanos / weights.len() as u128)
+    };
+
+    let mut shares: Vec<_> = init
+        .iter()
+        .map(|w| crate::checked_from_nanos(share_nanos(*w)).unwrap())
+        .collect();
+    let assigned: Duration = shares.iter().sum();
+    shares.push(total - assigned);
+    shares
+}
+
+/// Splits `d` into two parts, the first being `fraction` of `d`.
+///
+/// `fraction` is clamped to `[0, 1]` and a NaN `fraction` is treated as zero.
+/// The first part is rounded to the nearest nanosecond and the second part is
+/// the remainder, so the parts always sum to exactly `d`.
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{split::split_at_fraction, EasyDuration};
+///
+/// let (connect, read) = split_at_fraction(10.seconds(), 0.7);
+/// assert_eq!((connect, read), (7.seconds(), 3.seconds()));
+/// ```
+pub fn split_at_fraction(d: Duration, fraction: f64) -> (Duration, Duration) {
+    if fraction.is_nan() || fraction <= 0.0 {
+        return (Duration::ZERO, d);
+    }
+    if fraction >= 1.0 {
+        return (d, Duration::ZERO);
+    }
+    let nanos = d.as_nanos();
+    let first_nanos = ((nanos as f64 * fraction).round() as u128).min(nanos);
+    let first = crate::checked_from_nanos(first_nanos).unwrap();
+    (first, d - first)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EasyDuration;
+
+    #[test]
+    fn test_split_weighted() {
+        let shares = split_weighted(10.seconds(), &[1, 3, 1]);
+        assert_eq!(shares, [2.seconds(), 6.seconds(), 2.seconds()]);
+
+        let shares = split_weighted(Duration::from_nanos(10), &[1, 1, 1]);
+        assert_eq!(
+            shares,
+            [
+                Duration::from_nanos(3),
+                Duration::from_nanos(3),
+                Duration::from_nanos(4)
+            ]
+        );
+
+        let total = Duration::new(7, 123_456_789);
+        let shares = split_weighted(total, &[5, 0, 17, 2]);
+        assert_eq!(shares.iter().sum::<Duration>(), total);
+        assert_eq!(shares[1], Duration::ZERO);
+        assert!(shares[2] > shares[0] && shares[0] > shares[3]);
+
+        // All-zero weights split equally.
+        let shares = split_weighted(9.seconds(), &[0, 0, 0]);
+        assert_eq!(shares, [3.seconds(), 3.seconds(), 3.seconds()]);
+
+        assert_eq!(split_weighted(9.seconds(), &[4]), [9.seconds()]);
+        assert!(split_weighted(9.seconds(), &[]).is_empty());
+        assert_eq!(
+            split_weighted(Duration::MAX, &[u32::MAX, u32::MAX])
+                .iter()
+                .sum::<Duration>(),
+            Duration::MAX
+        );
+    }
+
+    #[test]
+    fn test_split_at_fraction() {
+        let ms = Duration::from_millis;
+
+        assert_eq!(
+            split_at_fraction(10.seconds(), 0.7),
+            (7.seconds(), 3.seconds())
+        );
+        assert_eq!(
+            split_at_fraction(10.seconds(), 0.5),
+            (5.seconds(), 5.seconds())
+        );
+        assert_eq!(
+            split_at_fraction(Duration::from_nanos(3), 0.5),
+            (Duration::from_nanos(2), Duration::from_nanos(1))
+        );
+        assert_eq!(
+            split_at_fraction(ms(100), 0.333),
+            (
+                ms(33) + Duration::from_micros(300),
+                ms(66) + Duration::from_micros(700)
+            )
+        );
+
+        // Clamping.
+        assert_eq!(
+            split_at_fraction(10.seconds(), 0.0),
+            (Duration::ZERO, 10.seconds())
+        );
+        assert_eq!(
+            split_at_fraction(10.seconds(), -1.0),
+            (Duration::ZERO, 10.seconds())
+        );
+        assert_eq!(
+            split_at_fraction(10.seconds(), 1.0),
+            (10.seconds(), Duration::ZERO)
+        );
+        assert_eq!(
+            split_at_fraction(10.seconds(), 2.5),
+            (10.seconds(), Duration::ZERO)
+        );
+        assert_eq!(
+            split_at_fraction(10.seconds(), f64::NAN),
+            (Duration::ZERO, 10.seconds())
+        );
+
+        for fraction in [0.1, 0.25, 0.7, 0.999] {
+            let (a, b) = split_at_fraction(Duration::MAX, fraction);
+            assert_eq!(a + b, Duration::MAX);
+        }
+    }
+}
@@ -0,0 +1,307 @@
+//! Sorting and summary statistics over collections of
+//! [`std::time::Duration`] values.
+
+use std::time::Duration;
+
+/// Sorts `v` in ascending order.
+pub fn sort_durations(v: &mut [Duration]) {
+    v.sort_unstable();
+}
+
+/// Sorts `v` in descending order.
+pub fn sorted_descending(v: &mut [Duration]) {
+    v.sort_unstable_by(|a, b| b.cmp(a));
+}
+
+/// Returns the median of `durations`, or `None` if the slice is empty.
+///
+/// For an even number of values the midpoint of the two middle values is
+/// returned. The input does not need to be sorted.
+pub fn median(durations: &[Duration]) -> Option<Duration> {
+    if durations.is_empty() {
+        return None;
+    }
+    let mut sorted = durations.to_vec();
+    sort_durations(&mut sorted);
+
+    let mid = sorted.len() / 2;
+    if sorted.len() % 2 == 1 {
+        Some(sorted[mid])
+    } else {
+        let (low, high) = (sorted[mid - 1], sorted[mid]);
+        Some(low + (high - low) / 2)
+    }
+}
+
+/// Returns the `p`-th percentile of `durations`, or `None` if the slice is
+/// empty.
+///
+/// `p` is clamped to `[0, 100]`. Values between ranks are linearly
+/// interpolated, so `percentile(v, 50.0)` equals [`median`]. The input does not
+/// need to be sorted.
+pub fn percentile(durations: &[Duration], p: f64) -> Option<Duration> {
+    if durations.is_empty() {
+        return None;
+    }
+    let mut sorted = durations.to_vec();
+    sort_durations(&mut sorted);
+    percentile_sorted(&sorted, p)
+}
+
+/// Like [`percentile`], but `sorted` must already be in ascending order.
+pub(crate) fn percentile_sorted(sorted: &[Duration], p: f64) -> Option<Duration> {
+    if sorted.is_empty() {
+        return None;
+    }
+    let rank = p.clamp(0.0, 100.0) / 100.0 * (sorted.len() - 1) as f64;
+    let index = rank.floor() as usize;
+    let low = sorted[index];
+    let Some(&high) = sorted.get(index + 1) else {
+        return Some(low);
+    };
+    let offset = ((high - low).as_nanos() as f64 * rank.fract()).round();
+    Some(low.saturating_add(crate::checked_from_nanos(offset as u128).unwrap_or(Duration::MAX)))
+}
+
+/// Returns the arithmetic mean of `durations`, or `None` if the slice is
+/// empty.
+///
+/// Values are accumulated as `u128` nanoseconds, so the sum cannot overflow.
+pub fn mean(durations: &[Duration]) -> Option<Duration> {
+    if durations.is_empty() {
+        return None;
+    }
+    let total: u128 = durations.iter().map(Duration::as_nanos).sum();
+    crate::checked_from_nanos(total / durations.len() as u128)
+}
+
+/// Returns the population standard deviation of `durations`, or `None` if the
+/// slice is empty.
+///
+/// The result is rounded to the nearest nanosecond.
+pub fn stddev(durations: &[Duration]) -> Option<Duration> {
+    let mean = mean(durations)?.as_nanos() as f64;
+    let variance = durations
+        .iter()
+        .map(|d| (d.as_nanos() as f64 - mean).powi(2))
+        .sum::<f64>()
+        / durations.len() as f64;
+    Some(crate::checked_from_nanos(variance.sqrt().round() as u128).unwrap_or(Duration::MAX))
+}
+
+/// Returns the power-of-ten bucket of `d` in seconds, that is
+/// `floor(log10(seconds))`.
+///
+/// For example 1ms is in bucket -3, 1s in bucket 0 and 100s in bucket 2. The
+/// bucket is computed from the exact nanosecond count, so values right at a
+/// power of ten are never misplaced by floating point error. A zero duration
+/// has no logarithm and returns [`i32::MIN`].
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use easyduration::{stats::log10_seconds_bucket, EasyDuration};
+///
+/// assert_eq!(log10_seconds_bucket(Duration::from_millis(1)), -3);
+/// assert_eq!(log10_seconds_bucket(100.seconds()), 2);
+/// ```
+pub fn log10_seconds_bucket(d: Duration) -> i32 {
+    match d.as_nanos().checked_ilog10() {
+        Some(log) => log as i32 - 9,
+        None => i32::MIN,
+    }
+}
+
+/// Online mean and variance of a stream of durations.
+///
+/// Uses Welford's algorithm on nanosecond floats, so samples don't need to be
+/// stored and the result stays numerically stable over long runs.
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{stats::RunningStats, EasyDuration};
+///
+/// let mut stats = RunningStats::new();
+/// stats.push(1.seconds());
+/// stats.push(3.seconds());
+/// assert_eq!(stats.count(), 2);
+/// assert_eq!(stats.mean(), 2.seconds());
+/// assert_eq!(stats.stddev(), 1.seconds());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct RunningStats {
+    count: u64,
+    mean: f64,
+    m2: f64,
+}
+
+impl RunningStats {
+    /// Creates empty stats.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a sample.
+    pub fn push(&mut self, d: Duration) {
+        let x = d.as_nanos() as f64;
+        self.count += 1;
+        let delta = x - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (x - self.mean);
+    }
+
+    /// Returns the number of samples.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the mean of all samples, rounded to the nearest nanosecond.
+    ///
+    /// Zero if there are no samples.
+    pub fn mean(&self) -> Duration {
+        crate::checked_from_nanos(self.mean.round() as u128).unwrap_or(Duration::MAX)
+    }
+
+    /// Returns the population variance in squared nanoseconds.
+    ///
+    /// Zero if there are no samples.
+    pub fn variance(&self) -> f64 {
+        if self.count == 0 {
+            0.0
+        } else {
+            self.m2 / self.count as f64
+        }
+    }
+
+    /// Returns the population standard deviation, rounded to the nearest
+    /// nanosecond.
+    pub fn stddev(&self) -> Duration {
+        crate::checked_from_nanos(self.variance().sqrt().round() as u128).unwrap_or(Duration::MAX)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EasyDuration;
+
+    #[test]
+    fn test_sort() {
+        let mut v = vec![3.seconds(), 1.seconds(), 2.seconds()];
+        sort_durations(&mut v);
+        assert_eq!(v, [1.seconds(), 2.seconds(), 3.seconds()]);
+
+        sorted_descending(&mut v);
+        assert_eq!(v, [3.seconds(), 2.seconds(), 1.seconds()]);
+    }
+
+    #[test]
+    fn test_median() {
+        assert_eq!(median(&[]), None);
+        assert_eq!(median(&[4.seconds()]), Some(4.seconds()));
+        assert_eq!(
+            median(&[5.seconds(), 1.seconds(), 3.seconds()]),
+            Some(3.seconds())
+        );
+        assert_eq!(
+            median(&[4.seconds(), 1.seconds(), 2.seconds(), 3.seconds()]),
+            Some(Duration::from_millis(2500))
+        );
+    }
+
+    #[test]
+    fn test_percentile() {
+        let data: Vec<_> = (1..=10u64).rev().map(|v| v.seconds()).collect();
+
+        assert_eq!(percentile(&[], 50.0), None);
+        assert_eq!(percentile(&data, 50.0), Some(Duration::from_millis(5500)));
+        assert_eq!(percentile(&data, 50.0), median(&data));
+        assert_eq!(percentile(&data, 90.0), Some(Duration::from_millis(9100)));
+        assert_eq!(percentile(&data, 100.0), Some(10.seconds()));
+        assert_eq!(percentile(&data, 0.0), Some(1.seconds()));
+
+        // Out of range percentiles are clamped.
+        assert_eq!(percentile(&data, 150.0), Some(10.seconds()));
+        assert_eq!(percentile(&data, -5.0), Some(1.seconds()));
+
+        // Spans of more than `u64::MAX` nanoseconds are interpolated too.
+        let data = [Duration::ZERO, 1000.years()];
+        let p50 = percentile(&data, 50.0).unwrap();
+        assert!(p50.abs_diff(median(&data).unwrap()) < Duration::from_millis(1));
+        assert_eq!(percentile(&data, 100.0), Some(1000.years()));
+    }
+
+    #[test]
+    fn test_mean_stddev() {
+        let data: Vec<_> = [2u64, 4, 4, 4, 5, 5, 7, 9]
+            .iter()
+            .map(|v| v.seconds())
+            .collect();
+
+        assert_eq!(mean(&[]), None);
+        assert_eq!(stddev(&[]), None);
+        assert_eq!(mean(&data), Some(5.seconds()));
+        assert_eq!(stddev(&data), Some(2.seconds()));
+
+        let data = [Duration::from_millis(100), Duration::from_millis(250)];
+        assert_eq!(mean(&data), Some(Duration::from_millis(175)));
+        assert_eq!(stddev(&data), Some(Duration::from_millis(75)));
+
+        // Sums beyond `Duration::MAX` are fine as long as the mean fits.
+        assert_eq!(mean(&[Duration::MAX, Duration::MAX]), Some(Duration::MAX));
+
+        // Deviations of more than `u64::MAX` nanoseconds don't saturate.
+        let deviation = stddev(&[Duration::ZERO, 2000.years()]).unwrap();
+        assert!(deviation.abs_diff(1000.years()) < Duration::from_millis(1));
+    }
+
+    #[test]
+    fn test_log10_seconds_bucket() {
+        let ms = Duration::from_millis;
+
+        assert_eq!(log10_seconds_bucket(Duration::from_nanos(1)), -9);
+        assert_eq!(log10_seconds_bucket(Duration::from_micros(1)), -6);
+        assert_eq!(log10_seconds_bucket(ms(1)), -3);
+        assert_eq!(log10_seconds_bucket(ms(9)), -3);
+        assert_eq!(log10_seconds_bucket(ms(10)), -2);
+        assert_eq!(log10_seconds_bucket(ms(999)), -1);
+        assert_eq!(log10_seconds_bucket(1.seconds()), 0);
+        assert_eq!(log10_seconds_bucket(99.seconds()), 1);
+        assert_eq!(log10_seconds_bucket(100.seconds()), 2);
+        assert_eq!(log10_seconds_bucket(Duration::MAX), 19);
+        assert_eq!(log10_seconds_bucket(Duration::ZERO), i32::MIN);
+    }
+
+    #[test]
+    fn test_running_stats() {
+        let empty = RunningStats::new();
+        assert_eq!(empty.count(), 0);
+        assert_eq!(empty.mean(), Duration::ZERO);
+        assert_eq!(empty.variance(), 0.0);
+
+        let samples: Vec<_> = (1..=1000u64)
+            .map(|i| Duration::from_micros(i * i % 7919 + 100))
+            .collect();
+        let mut stats = RunningStats::new();
+        for d in &samples {
+            stats.push(*d);
+        }
+        assert_eq!(stats.count(), 1000);
+
+        let close = |a: Duration, b: Duration| a.abs_diff(b) <= Duration::from_nanos(1);
+        assert!(close(stats.mean(), mean(&samples).unwrap()));
+        assert!(close(stats.stddev(), stddev(&samples).unwrap()));
+
+        let mut single = RunningStats::new();
+        single.push(5.seconds());
+        assert_eq!(single.mean(), 5.seconds());
+        assert_eq!(single.variance(), 0.0);
+
+        let mut wide = RunningStats::new();
+        wide.push(Duration::ZERO);
+        wide.push(2000.years());
+        assert!(wide.stddev().abs_diff(1000.years()) < Duration::from_millis(1));
+    }
+}
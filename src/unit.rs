@@ -0,0 +1,337 @@
+//! The [`Unit`] enum describing the time units understood by the crate.
+
+use std::{fmt, str::FromStr, time::Duration};
+
+use crate::{convert::OverflowError, EasyDuration, ParseError};
+
+/// A unit of time.
+///
+/// A day is defined as 24 hours, a week as 7 days and a year as 365 days,
+/// matching [`EasyDuration`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Unit {
+    Nanoseconds,
+    Microseconds,
+    Milliseconds,
+    Seconds,
+    Minutes,
+    Hours,
+    Days,
+    Weeks,
+    Years,
+}
+
+impl Unit {
+    /// All units, from smallest to largest.
+    pub const ALL: [Unit; 9] = [
+        Unit::Nanoseconds,
+        Unit::Microseconds,
+        Unit::Milliseconds,
+        Unit::Seconds,
+        Unit::Minutes,
+        Unit::Hours,
+        Unit::Days,
+        Unit::Weeks,
+        Unit::Years,
+    ];
+
+    /// Returns the length of a single unit.
+    pub const fn duration(self) -> Duration {
+        match self {
+            Unit::Nanoseconds => Duration::from_nanos(1),
+            Unit::Microseconds => Duration::from_micros(1),
+            Unit::Milliseconds => Duration::from_millis(1),
+            Unit::Seconds => Duration::from_secs(1),
+            Unit::Minutes => Duration::from_secs(60),
+            Unit::Hours => Duration::from_secs(60 * 60),
+            Unit::Days => Duration::from_secs(60 * 60 * 24),
+            Unit::Weeks => Duration::from_secs(60 * 60 * 24 * 7),
+            Unit::Years => Duration::from_secs(60 * 60 * 24 * 365),
+        }
+    }
+
+    /// Returns the canonical short suffix of the unit, like `"ms"` or `"h"`.
+    pub const fn suffix(self) -> &'static str {
+        match self {
+            Unit::Nanoseconds => "ns",
+            Unit::Microseconds => "us",
+            Unit::Milliseconds => "ms",
+            Unit::Seconds => "s",
+            Unit::Minutes => "m",
+            Unit::Hours => "h",
+            Unit::Days => "d",
+            Unit::Weeks => "w",
+            Unit::Years => "y",
+        }
+    }
+
+    /// Returns `n` units, or `None` if the result overflows [`Duration`].
+    pub(crate) fn checked_mul(self, n: u64) -> Option<Duration> {
+        crate::checked_from_nanos(self.duration().as_nanos().checked_mul(n.into())?)
+    }
+}
+
+impl fmt::Display for Unit {
+    /// Renders the canonical short suffix, see [`Unit::suffix`].
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.suffix())
+    }
+}
+
+impl FromStr for Unit {
+    type Err = ParseError;
+
+    /// Parses a unit from its short or long name, like `"ms"` or
+    /// `"milliseconds"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let unit = match s {
+            "ns" | "nanos" | "nanosecond" | "nanoseconds" => Unit::Nanoseconds,
+            "us" | "µs" | "micros" | "microsecond" | "microseconds" => Unit::Microseconds,
+            "ms" | "millis" | "millisecond" | "milliseconds" => Unit::Milliseconds,
+            "s" | "sec" | "secs" | "second" | "seconds" => Unit::Seconds,
+            "m" | "min" | "mins" | "minute" | "minutes" => Unit::Minutes,
+            "h" | "hr" | "hrs" | "hour" | "hours" => Unit::Hours,
+            "d" | "day" | "days" => Unit::Days,
+            "w" | "week" | "weeks" => Unit::Weeks,
+            "y" | "year" | "years" => Unit::Years,
+            _ => return Err(ParseError::UnknownUnit(s.to_string())),
+        };
+        Ok(unit)
+    }
+}
+
+/// Sums `(value, unit)` components into a single duration.
+///
+/// # Panics
+///
+/// Panics if the sum overflows [`Duration`]. See [`try_from_components`] for
+/// a non-panicking variant.
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{unit::from_components, EasyDuration, Unit};
+///
+/// let d = from_components(&[(1, Unit::Hours), (30, Unit::Minutes)]);
+/// assert_eq!(d, 90.minutes());
+/// ```
+pub fn from_components(components: &[(u64, Unit)]) -> Duration {
+    try_from_components(components).expect("overflow when constructing duration")
+}
+
+/// Sums `(value, unit)` components into a single duration, returning an error
+/// if the sum overflows [`Duration`].
+pub fn try_from_components(components: &[(u64, Unit)]) -> Result<Duration, OverflowError> {
+    components
+        .iter()
+        .try_fold(Duration::ZERO, |total, (value, unit)| {
+            total.checked_add(unit.checked_mul(*value)?)
+        })
+        .ok_or(OverflowError)
+}
+
+/// Converts each of `values` into a duration of that many `unit`s.
+///
+/// As with the [`EasyDuration`] methods, the absolute value of signed
+/// integers is used.
+///
+/// # Panics
+///
+/// Panics if a duration overflows [`Duration`].
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{unit::from_slice, EasyDuration, Unit};
+///
+/// let intervals = from_slice(&[30u32, 60, 90], Unit::Seconds);
+/// assert_eq!(intervals, [30.seconds(), 1.minutes(), 90.seconds()]);
+/// ```
+pub fn from_slice<T: EasyDuration + Copy>(values: &[T], unit: Unit) -> Vec<Duration> {
+    values
+        .iter()
+        .map(|value| {
+            unit.checked_mul(value.seconds().as_secs())
+                .expect("overflow when constructing duration")
+        })
+        .collect()
+}
+
+/// Returns `d` as a count of the largest unit that represents it exactly.
+///
+/// For example 3600 seconds are `(1, Unit::Hours)` while 3601 seconds stay
+/// `(3601, Unit::Seconds)`. A zero duration is `(0, Unit::Seconds)`.
+///
+/// Returns `None` if the count doesn't fit into a `u64`, which can only happen
+/// for durations of more than 584 years with sub-second precision, like
+/// [`Duration::MAX`].
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{unit::exact_unit, EasyDuration, Unit};
+///
+/// assert_eq!(exact_unit(90.minutes()), Some((90, Unit::Minutes)));
+/// assert_eq!(exact_unit(3601.seconds()), Some((3601, Unit::Seconds)));
+/// ```
+pub fn exact_unit(d: Duration) -> Option<(u64, Unit)> {
+    if d.is_zero() {
+        return Some((0, Unit::Seconds));
+    }
+    let nanos = d.as_nanos();
+    let unit = *Unit::ALL
+        .iter()
+        .rev()
+        .find(|unit| nanos.is_multiple_of(unit.duration().as_nanos()))
+        .unwrap();
+    let count = nanos / unit.duration().as_nanos();
+    Some((count.try_into().ok()?, unit))
+}
+
+/// Returns `numerator / denominator` of `unit`, like `90/60` minutes.
+///
+/// The computation is exact and the result is truncated to whole
+/// nanoseconds.
+///
+/// # Panics
+///
+/// Panics if `denominator` is zero or the result overflows [`Duration`].
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{unit::ratio, EasyDuration, Unit};
+///
+/// assert_eq!(ratio(90, 60, Unit::Minutes), 90.seconds());
+/// ```
+pub fn ratio(numerator: u64, denominator: u64, unit: Unit) -> Duration {
+    assert!(denominator != 0, "ratio denominator must not be zero");
+    let nanos = unit.duration().as_nanos() * u128::from(numerator) / u128::from(denominator);
+    crate::checked_from_nanos(nanos).expect("overflow when constructing duration")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_from_str_round_trip() {
+        for unit in Unit::ALL {
+            assert_eq!(unit.to_string().parse::<Unit>(), Ok(unit));
+        }
+
+        assert_eq!(Unit::Milliseconds.to_string(), "ms");
+        assert_eq!("µs".parse(), Ok(Unit::Microseconds));
+        assert_eq!("minutes".parse(), Ok(Unit::Minutes));
+        assert_eq!("hr".parse(), Ok(Unit::Hours));
+        assert_eq!(
+            "fortnight".parse::<Unit>(),
+            Err(ParseError::UnknownUnit("fortnight".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_from_components() {
+        use crate::EasyDuration;
+
+        assert_eq!(
+            from_components(&[(1, Unit::Hours), (30, Unit::Minutes)]),
+            90.minutes()
+        );
+        assert_eq!(
+            from_components(&[
+                (1, Unit::Seconds),
+                (500, Unit::Milliseconds),
+                (1, Unit::Seconds)
+            ]),
+            Duration::from_millis(2500)
+        );
+        assert_eq!(from_components(&[]), Duration::ZERO);
+
+        assert_eq!(try_from_components(&[(2, Unit::Weeks)]), Ok(14.days()));
+        assert_eq!(
+            try_from_components(&[(u64::MAX, Unit::Minutes)]),
+            Err(OverflowError)
+        );
+        assert_eq!(
+            try_from_components(&[(u64::MAX, Unit::Seconds), (1, Unit::Seconds)]),
+            Err(OverflowError)
+        );
+    }
+
+    #[test]
+    fn test_from_slice() {
+        let minutes = from_slice(&[1u32, 5, 90], Unit::Minutes);
+        let expected: Vec<_> = [60u64, 300, 5400].map(Duration::from_secs).into();
+        assert_eq!(minutes, expected);
+
+        assert_eq!(
+            from_slice(&[2i64, -3], Unit::Hours),
+            [Duration::from_secs(7200), Duration::from_secs(10_800)]
+        );
+        assert_eq!(
+            from_slice(&[1500u16], Unit::Milliseconds),
+            [Duration::from_millis(1500)]
+        );
+        assert_eq!(from_slice::<u8>(&[], Unit::Seconds), []);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow")]
+    fn test_from_slice_overflow() {
+        from_slice(&[u64::MAX], Unit::Years);
+    }
+
+    #[test]
+    fn test_ratio() {
+        use crate::EasyDuration;
+
+        assert_eq!(ratio(90, 60, Unit::Minutes), 90.seconds());
+        assert_eq!(ratio(3, 2, Unit::Seconds), Duration::from_millis(1500));
+        assert_eq!(
+            ratio(1, 3, Unit::Seconds),
+            Duration::from_nanos(333_333_333)
+        );
+        assert_eq!(ratio(0, 5, Unit::Hours), Duration::ZERO);
+        assert_eq!(ratio(7, 1, Unit::Days), Unit::Weeks.duration());
+    }
+
+    #[test]
+    #[should_panic(expected = "denominator must not be zero")]
+    fn test_ratio_zero_denominator() {
+        ratio(1, 0, Unit::Seconds);
+    }
+
+    #[test]
+    fn test_exact_unit() {
+        use crate::EasyDuration;
+
+        assert_eq!(exact_unit(3600.seconds()), Some((1, Unit::Hours)));
+        assert_eq!(exact_unit(90.seconds()), Some((90, Unit::Seconds)));
+        assert_eq!(exact_unit(3601.seconds()), Some((3601, Unit::Seconds)));
+        assert_eq!(exact_unit(120.seconds()), Some((2, Unit::Minutes)));
+        assert_eq!(exact_unit(14.days()), Some((2, Unit::Weeks)));
+        assert_eq!(exact_unit(730.days()), Some((2, Unit::Years)));
+        assert_eq!(
+            exact_unit(Duration::from_millis(1500)),
+            Some((1500, Unit::Milliseconds))
+        );
+        assert_eq!(
+            exact_unit(Duration::from_nanos(1001)),
+            Some((1001, Unit::Nanoseconds))
+        );
+        assert_eq!(exact_unit(Duration::ZERO), Some((0, Unit::Seconds)));
+        assert_eq!(
+            exact_unit(Duration::from_secs(u64::MAX)),
+            Some((u64::MAX, Unit::Seconds))
+        );
+
+        // Sub-second precision beyond 584 years doesn't fit any unit.
+        assert_eq!(exact_unit(Duration::MAX), None);
+        assert_eq!(exact_unit(Duration::new(600 * 365 * 86_400, 1)), None);
+        assert_eq!(
+            exact_unit(Duration::new(600 * 365 * 86_400, 1_000_000)),
+            Some((18_921_600_000_001, Unit::Milliseconds))
+        );
+    }
+}
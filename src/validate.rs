@@ -0,0 +1,193 @@
+//! Validation of [`std::time::Duration`] values.
+
+use std::{fmt, ops::Range, time::Duration};
+
+use crate::format::humanize;
+
+/// Error returned when a duration is zero where a non-zero duration is
+/// required.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ZeroDurationError;
+
+impl fmt::Display for ZeroDurationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("duration must not be zero")
+    }
+}
+
+impl std::error::Error for ZeroDurationError {}
+
+/// Error returned by [`validate_range`] when a duration is out of bounds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RangeError {
+    /// The duration is shorter than the minimum.
+    BelowMin { min: Duration },
+    /// The duration is longer than the maximum.
+    AboveMax { max: Duration },
+}
+
+impl fmt::Display for RangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RangeError::BelowMin { min } => {
+                write!(f, "duration must be at least {}", humanize(*min))
+            }
+            RangeError::AboveMax { max } => {
+                write!(f, "duration must be at most {}", humanize(*max))
+            }
+        }
+    }
+}
+
+impl std::error::Error for RangeError {}
+
+/// Returns `d` if it is within `[min, max]`, or an error naming the violated
+/// bound.
+///
+/// # Example
+///
+/// ```rust
+/// use easyduration::{validate::{validate_range, RangeError}, EasyDuration};
+///
+/// let lease = validate_range(30.seconds(), 60.seconds(), 365.days());
+/// assert_eq!(lease, Err(RangeError::BelowMin { min: 60.seconds() }));
+/// ```
+pub fn validate_range(d: Duration, min: Duration, max: Duration) -> Result<Duration, RangeError> {
+    debug_assert!(min <= max, "validate_range: min must not exceed max");
+    if d < min {
+        Err(RangeError::BelowMin { min })
+    } else if d > max {
+        Err(RangeError::AboveMax { max })
+    } else {
+        Ok(d)
+    }
+}
+
+/// A warning from [`sanity_check`] about a duration that is likely given in
+/// the wrong unit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Warning {
+    /// The suspicious duration.
+    pub value: Duration,
+    /// The same number read in a different unit, if that lands in the
+    /// expected range.
+    pub suggestion: Option<Duration>,
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "duration {} is far outside the expected range",
+            humanize(self.value)
+        )?;
+        if let Some(suggestion) = self.suggestion {
+            write!(f, ", did you mean {}?", humanize(suggestion))?;
+        }
+        Ok(())
+    }
+}
+
+/// Checks whether `d` is plausible for a value expected in `expected_range`.
+///
+/// Returns a [`Warning`] if `d` is more than ten times below the start or
+/// above the end of the range. The warning suggests reading the number in a
+/// different unit if that lands in the range, trying milliseconds and
+/// seconds, seconds and minutes, microseconds and seconds and finally seconds
+/// and hours, in both directions. This catches the classic mix-up of
+/// `"300"` meant as milliseconds but read as seconds.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use easyduration::{validate::sanity_check, EasyDuration};
+///
+/// let range = Duration::from_millis(10)..2.seconds();
+/// let warning = sanity_check(300.seconds(), range).unwrap();
+/// assert_eq!(warning.suggestion, Some(Duration::from_millis(300)));
+/// ```
+pub fn sanity_check(d: Duration, expected_range: Range<Duration>) -> Option<Warning> {
+    let nanos = d.as_nanos();
+    let (start, end) = (
+        expected_range.start.as_nanos(),
+        expected_range.end.as_nanos(),
+    );
+    if nanos.saturating_mul(10) >= start && nanos / 10 < end {
+        return None;
+    }
+
+    let suggestion = [1_000, 60, 1_000_000, 3_600]
+        .into_iter()
+        .flat_map(|factor| [nanos / factor, nanos.saturating_mul(factor)])
+        .filter_map(crate::checked_from_nanos)
+        .find(|candidate| expected_range.contains(candidate));
+    Some(Warning {
+        value: d,
+        suggestion,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EasyDuration;
+
+    #[test]
+    fn test_validate_range() {
+        let (min, max) = (60.seconds(), 365.days());
+
+        assert_eq!(validate_range(1.hours(), min, max), Ok(1.hours()));
+        assert_eq!(validate_range(min, min, max), Ok(min));
+        assert_eq!(validate_range(max, min, max), Ok(max));
+
+        let err = validate_range(59.seconds(), min, max).unwrap_err();
+        assert_eq!(err, RangeError::BelowMin { min });
+        assert_eq!(err.to_string(), "duration must be at least 1m");
+
+        let err = validate_range(366.days(), min, max).unwrap_err();
+        assert_eq!(err, RangeError::AboveMax { max });
+        assert_eq!(err.to_string(), "duration must be at most 1y");
+    }
+
+    #[test]
+    fn test_sanity_check() {
+        let ms = Duration::from_millis;
+        let range = ms(10)..2.seconds();
+
+        assert_eq!(sanity_check(ms(500), range.clone()), None);
+        // Slightly out of range is not suspicious.
+        assert_eq!(sanity_check(5.seconds(), range.clone()), None);
+        assert_eq!(sanity_check(ms(2), range.clone()), None);
+
+        // Seconds meant as milliseconds.
+        let warning = sanity_check(300.seconds(), range.clone()).unwrap();
+        assert_eq!(
+            warning,
+            Warning {
+                value: 300.seconds(),
+                suggestion: Some(ms(300))
+            }
+        );
+        assert_eq!(
+            warning.to_string(),
+            "duration 5m is far outside the expected range, did you mean 300ms?"
+        );
+
+        // Milliseconds meant as seconds.
+        let warning = sanity_check(Duration::from_micros(30), 1.seconds()..1.minutes()).unwrap();
+        assert_eq!(warning.suggestion, Some(30.seconds()));
+
+        // Seconds meant as minutes.
+        let warning = sanity_check(5.seconds(), 1.minutes()..1.hours()).unwrap();
+        assert_eq!(warning.suggestion, Some(5.minutes()));
+
+        // No unit fits.
+        let warning = sanity_check(400.days(), range).unwrap();
+        assert_eq!(warning.suggestion, None);
+        assert_eq!(
+            warning.to_string(),
+            "duration 1y 35d is far outside the expected range"
+        );
+    }
+}
@@ -0,0 +1,121 @@
+//! Aggregation of durations over fixed time windows.
+
+use std::time::{Duration, Instant};
+
+/// Sums durations per fixed, non-overlapping time window.
+///
+/// Windows are aligned to the instant of the first value added. Adding a
+/// value whose instant falls into a later window starts a new bucket. Values
+/// from earlier windows are ignored, while instants before the first value
+/// count towards the first window.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::{Duration, Instant};
+/// use easyduration::{window::TumblingWindow, EasyDuration};
+///
+/// let start = Instant::now();
+/// let mut window = TumblingWindow::new(1.seconds());
+/// window.add(start, Duration::from_millis(200));
+/// window.add(start + Duration::from_millis(500), Duration::from_millis(300));
+/// assert_eq!(window.current_bucket_total(), Duration::from_millis(500));
+///
+/// window.add(start + 1.seconds(), Duration::from_millis(100));
+/// assert_eq!(window.current_bucket_total(), Duration::from_millis(100));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TumblingWindow {
+    window: Duration,
+    origin: Option<Instant>,
+    bucket: u128,
+    total: Duration,
+}
+
+impl TumblingWindow {
+    /// Creates an empty aggregator with windows of length `window`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero.
+    pub fn new(window: Duration) -> Self {
+        assert!(!window.is_zero(), "window must not be zero");
+        Self {
+            window,
+            origin: None,
+            bucket: 0,
+            total: Duration::ZERO,
+        }
+    }
+
+    /// Returns the length of a window.
+    pub fn window(&self) -> Duration {
+        self.window
+    }
+
+    /// Adds `value`, observed at `at`, to its window.
+    ///
+    /// Totals saturate at [`Duration::MAX`].
+    pub fn add(&mut self, at: Instant, value: Duration) {
+        let origin = *self.origin.get_or_insert(at);
+        let bucket = at.saturating_duration_since(origin).as_nanos() / self.window.as_nanos();
+        if bucket > self.bucket {
+            self.bucket = bucket;
+            self.total = Duration::ZERO;
+        }
+        if bucket == self.bucket {
+            self.total = self.total.saturating_add(value);
+        }
+    }
+
+    /// Returns the sum of the values in the most recent window.
+    pub fn current_bucket_total(&self) -> Duration {
+        self.total
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EasyDuration;
+
+    #[test]
+    fn test_tumbling_window() {
+        let ms = Duration::from_millis;
+        let start = Instant::now();
+        let at = |millis| start + ms(millis);
+        let mut window = TumblingWindow::new(1.seconds());
+        assert_eq!(window.current_bucket_total(), Duration::ZERO);
+
+        window.add(at(0), ms(100));
+        window.add(at(400), ms(200));
+        window.add(at(999), ms(300));
+        assert_eq!(window.current_bucket_total(), ms(600));
+
+        // Crossing the boundary starts a new bucket.
+        window.add(at(1000), ms(50));
+        assert_eq!(window.current_bucket_total(), ms(50));
+        window.add(at(1500), ms(50));
+        assert_eq!(window.current_bucket_total(), ms(100));
+
+        // Values from an earlier window are ignored.
+        window.add(at(900), ms(500));
+        assert_eq!(window.current_bucket_total(), ms(100));
+
+        // Skipping windows resets as well.
+        window.add(at(5200), ms(10));
+        assert_eq!(window.current_bucket_total(), ms(10));
+
+        // Instants before the origin count towards the first window.
+        let mut early = TumblingWindow::new(1.seconds());
+        early.add(at(500), ms(1));
+        early.add(at(0), ms(2));
+        assert_eq!(early.current_bucket_total(), ms(3));
+    }
+
+    #[test]
+    #[should_panic(expected = "window must not be zero")]
+    fn test_zero_window() {
+        TumblingWindow::new(Duration::ZERO);
+    }
+}